@@ -1,5 +1,5 @@
 use criterion::{black_box, criterion_group, criterion_main, Criterion, BenchmarkId};
-use bmssp_core::block_heap::{BlockHeap, FastBlockHeap};
+use bmssp_core::block_heap::{BlockHeap, FastBlockHeap, BucketHeap};
 
 fn bench_push_operations(c: &mut Criterion) {
     let mut group = c.benchmark_group("push_operations");
@@ -34,8 +34,23 @@ fn bench_push_operations(c: &mut Criterion) {
                 })
             },
         );
+
+        // Benchmark Dial's-algorithm BucketHeap (integer weights)
+        group.bench_with_input(
+            BenchmarkId::new("BucketHeap_Dial", size),
+            size,
+            |b, &size| {
+                b.iter(|| {
+                    let mut heap = BucketHeap::new(size, 1);
+                    for i in 0..size {
+                        heap.push(i, i);
+                    }
+                    black_box(&heap);
+                })
+            },
+        );
     }
-    
+
     group.finish();
 }
 
@@ -78,8 +93,26 @@ fn bench_decrease_key_operations(c: &mut Criterion) {
                 })
             },
         );
+
+        // Benchmark Dial's-algorithm BucketHeap (integer weights)
+        group.bench_with_input(
+            BenchmarkId::new("BucketHeap_Dial", size),
+            size,
+            |b, &size| {
+                let mut heap = BucketHeap::new(size * 10, 1);
+                for i in 0..size {
+                    heap.push(i, i * 10);
+                }
+                b.iter(|| {
+                    for i in 0..size {
+                        heap.decrease_key(i, i * 5);
+                    }
+                    black_box(&heap);
+                })
+            },
+        );
     }
-    
+
     group.finish();
 }
 
@@ -128,8 +161,28 @@ fn bench_pop_block_operations(c: &mut Criterion) {
                 })
             },
         );
+
+        // Benchmark Dial's-algorithm BucketHeap (integer weights)
+        group.bench_with_input(
+            BenchmarkId::new("BucketHeap_Dial", format!("{}_block{}", total, block)),
+            &(total, block),
+            |b, &(total_size, block_size)| {
+                b.iter(|| {
+                    let mut heap = BucketHeap::new(total_size, 1);
+                    for i in 0..total_size {
+                        heap.push(i, i);
+                    }
+                    let mut count = 0;
+                    while !heap.is_empty() && count < total_size {
+                        let (block_result, _) = heap.pop_block(block_size);
+                        count += block_result.len();
+                        black_box(&block_result);
+                    }
+                })
+            },
+        );
     }
-    
+
     group.finish();
 }
 
@@ -0,0 +1,205 @@
+use num_traits::Float;
+
+use crate::bmssp::{bmssp_astar, bmssp_sssp};
+use crate::csr::CsrGraph;
+use crate::error::Result;
+use crate::matrix::Matrix;
+
+/// Default landmark count for [`astar_sssp`]'s one-shot convenience entry
+/// point. Callers running many queries against the same graph should build
+/// an [`AltIndex`] once via [`AltIndex::build`] and reuse it instead, since
+/// that's the whole point of amortizing the landmark preprocessing.
+const DEFAULT_LANDMARK_COUNT: usize = 8;
+
+/// Precomputed ALT (A*, Landmarks, Triangle inequality) landmark tables
+///
+/// Holds, for a small set of landmark vertices chosen farthest-first,
+/// the distance from every vertex to each landmark and from each landmark
+/// to every vertex. [`heuristic`](Self::heuristic) combines these via the
+/// triangle inequality into an admissible lower bound usable as an A*
+/// heuristic for any source/target pair on the same graph, so the
+/// preprocessing (two full SSSPs per landmark) is paid once and reused
+/// across many point-to-point queries.
+pub struct AltIndex<T> {
+    landmarks: Vec<usize>,
+    /// `forward[l][v]` = distance from landmark `l` to `v`
+    forward: Matrix<T>,
+    /// `backward[l][v]` = distance from `v` to landmark `l`
+    backward: Matrix<T>,
+}
+
+impl<T> AltIndex<T>
+where
+    T: Float + Copy + Send + Sync + 'static,
+{
+    /// Choose up to `num_landmarks` landmarks farthest-first (each new
+    /// landmark is the vertex with the largest distance to the nearest
+    /// landmark chosen so far) and precompute forward/backward distance
+    /// tables for all of them.
+    pub fn build(
+        graph: &CsrGraph,
+        weights: &[T],
+        num_landmarks: usize,
+        enabled: Option<&[bool]>,
+    ) -> Result<Self> {
+        let n = graph.num_vertices();
+        let num_landmarks = num_landmarks.min(n);
+
+        if n == 0 || num_landmarks == 0 {
+            return Ok(Self {
+                landmarks: Vec::new(),
+                forward: Matrix::new(Vec::new(), n),
+                backward: Matrix::new(Vec::new(), n),
+            });
+        }
+
+        let (rev_graph, edge_map) = graph.transpose();
+        let rev_weights: Vec<T> = edge_map.iter().map(|&e| weights[e]).collect();
+        let rev_enabled: Option<Vec<bool>> =
+            enabled.map(|mask| edge_map.iter().map(|&e| mask[e]).collect());
+
+        let mut landmarks = Vec::with_capacity(num_landmarks);
+        let mut min_dist_to_landmarks = vec![T::infinity(); n];
+        let mut forward_rows: Vec<T> = Vec::with_capacity(num_landmarks * n);
+        let mut backward_rows: Vec<T> = Vec::with_capacity(num_landmarks * n);
+        let mut next_landmark = 0usize;
+
+        for i in 0..num_landmarks {
+            landmarks.push(next_landmark);
+
+            let fwd = bmssp_sssp(graph, weights, next_landmark, enabled)?;
+            let bwd = bmssp_sssp(&rev_graph, &rev_weights, next_landmark, rev_enabled.as_deref())?;
+
+            for (slot, &f) in min_dist_to_landmarks.iter_mut().zip(fwd.iter()) {
+                if f < *slot {
+                    *slot = f;
+                }
+            }
+
+            forward_rows.extend_from_slice(&fwd);
+            backward_rows.extend_from_slice(&bwd);
+
+            if i + 1 < num_landmarks {
+                let mut farthest = None;
+                let mut farthest_dist = T::neg_infinity();
+                for (v, &d) in min_dist_to_landmarks.iter().enumerate() {
+                    if d.is_finite() && d > farthest_dist {
+                        farthest_dist = d;
+                        farthest = Some(v);
+                    }
+                }
+                match farthest {
+                    Some(v) => next_landmark = v,
+                    // No vertex gained new information (graph too small or
+                    // disconnected from every landmark so far) — stop early
+                    // with fewer landmarks than requested.
+                    None => break,
+                }
+            }
+        }
+
+        Ok(Self {
+            landmarks,
+            forward: Matrix::new(forward_rows, n),
+            backward: Matrix::new(backward_rows, n),
+        })
+    }
+
+    /// Number of landmarks actually selected (may be less than requested
+    /// for small or disconnected graphs)
+    pub fn landmark_count(&self) -> usize {
+        self.landmarks.len()
+    }
+
+    /// Admissible lower bound on the distance from `v` to `target`:
+    /// `max` over landmarks `L` of `max(d(L,t) - d(L,v), d(v,L) - d(t,L))`
+    pub fn heuristic(&self, v: usize, target: usize) -> T {
+        let mut best = T::zero();
+        for l in 0..self.landmarks.len() {
+            let d_lt = self.forward[l][target];
+            let d_lv = self.forward[l][v];
+            let d_vl = self.backward[l][v];
+            let d_tl = self.backward[l][target];
+
+            let via_forward = d_lt - d_lv;
+            let via_backward = d_vl - d_tl;
+            let candidate = if via_forward > via_backward {
+                via_forward
+            } else {
+                via_backward
+            };
+            if candidate > best {
+                best = candidate;
+            }
+        }
+        best
+    }
+}
+
+/// Goal-directed single-pair shortest path using the ALT heuristic
+///
+/// Builds a fresh [`AltIndex`] (see its docs for why that's wasteful to
+/// repeat across many queries) and drives [`bmssp_astar`] with
+/// `h(v) = index.heuristic(v, target)`, terminating as soon as `target` is
+/// settled instead of computing a full distance array.
+pub fn astar_sssp<T>(
+    graph: &CsrGraph,
+    weights: &[T],
+    source: usize,
+    target: usize,
+    enabled: Option<&[bool]>,
+) -> Result<Option<(T, Vec<usize>)>>
+where
+    T: Float + Copy + Send + Sync + 'static,
+{
+    let index = AltIndex::build(graph, weights, DEFAULT_LANDMARK_COUNT, enabled)?;
+    bmssp_astar(
+        graph,
+        weights,
+        source,
+        target,
+        |v| index.heuristic(v, target),
+        enabled,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_astar_sssp_picks_shortest_of_two_routes() {
+        // 0 -> 1 -> 3 costs 1+5=6, 0 -> 2 -> 3 costs 2+1=3.
+        let indptr = vec![0, 2, 3, 4, 4];
+        let indices = vec![1, 2, 3, 3];
+        let graph = CsrGraph::new(4, indptr, indices).unwrap();
+        let weights = vec![1.0f32, 2.0, 5.0, 1.0];
+
+        let (cost, path) = astar_sssp(&graph, &weights, 0, 3, None).unwrap().unwrap();
+        assert_eq!(cost, 3.0);
+        assert_eq!(path, vec![0, 2, 3]);
+    }
+
+    #[test]
+    fn test_astar_sssp_unreachable_target_returns_none() {
+        let indptr = vec![0, 1, 1, 1];
+        let indices = vec![1];
+        let graph = CsrGraph::new(3, indptr, indices).unwrap();
+        let weights = vec![1.0f32];
+
+        assert!(astar_sssp(&graph, &weights, 0, 2, None).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_alt_index_heuristic_matches_true_distance_on_chain() {
+        // Chain 0 -> 1 -> 2 -> 3, weights 1 each; h(0, 3) should equal the
+        // true distance 3 once landmark 3 itself is chosen.
+        let indptr = vec![0, 1, 2, 3, 3];
+        let indices = vec![1, 2, 3];
+        let graph = CsrGraph::new(4, indptr, indices).unwrap();
+        let weights = vec![1.0f32, 1.0, 1.0];
+
+        let index = AltIndex::build(&graph, &weights, 4, None).unwrap();
+        assert_eq!(index.heuristic(0, 3), 3.0);
+    }
+}
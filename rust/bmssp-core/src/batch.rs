@@ -0,0 +1,62 @@
+use crate::csr::CsrGraph;
+use crate::distance_matrix::bmssp_distance_matrix;
+use crate::error::Result;
+use num_traits::Float;
+
+/// Run many independent single-source BMSSP solves and return one distance
+/// row per source
+///
+/// This is the throughput-oriented, first-class counterpart to the
+/// hand-rolled `for source in sources { ... }` loop pattern: under the
+/// `parallel` feature, [`bmssp_distance_matrix`] (which this delegates to)
+/// distributes `sources` across rayon threads with `map_init`, so each
+/// rayon job lazily allocates one [`crate::bmssp::BmsspState`] and reuses
+/// it across the queries in that job instead of reallocating per source
+/// (rayon may split the work into more jobs than there are threads, so a
+/// thread can allocate more than one) — the allocation that dominates when answering thousands of
+/// queries against the same graph. Without the feature, a single state is
+/// reused sequentially. `graph`/`weights` are borrowed read-only so they
+/// can be shared across threads safely.
+pub fn bmssp_sssp_batch<T>(
+    graph: &CsrGraph,
+    weights: &[T],
+    sources: &[usize],
+    enabled: Option<&[bool]>,
+) -> Result<Vec<Vec<T>>>
+where
+    T: Float + Copy + Send + Sync + 'static,
+{
+    let matrix = bmssp_distance_matrix(graph, weights, sources, None, enabled)?;
+    Ok((0..matrix.rows()).map(|i| matrix[i].to_vec()).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bmssp_sssp_batch_matches_per_source_loop() {
+        // Chain: 0 -> 1 -> 2, weight 1.0 each
+        let indptr = vec![0, 1, 2, 2];
+        let indices = vec![1, 2];
+        let graph = CsrGraph::new(3, indptr, indices).unwrap();
+        let weights = vec![1.0f32, 1.0f32];
+
+        let rows = bmssp_sssp_batch(&graph, &weights, &[0, 1, 2], None).unwrap();
+        assert_eq!(rows.len(), 3);
+        assert_eq!(rows[0], vec![0.0, 1.0, 2.0]);
+        assert_eq!(rows[1], vec![f32::INFINITY, 0.0, 1.0]);
+        assert_eq!(rows[2], vec![f32::INFINITY, f32::INFINITY, 0.0]);
+    }
+
+    #[test]
+    fn test_bmssp_sssp_batch_empty_sources_returns_no_rows() {
+        let indptr = vec![0, 0];
+        let indices: Vec<usize> = vec![];
+        let graph = CsrGraph::new(1, indptr, indices).unwrap();
+        let weights: Vec<f32> = vec![];
+
+        let rows = bmssp_sssp_batch(&graph, &weights, &[], None).unwrap();
+        assert!(rows.is_empty());
+    }
+}
@@ -0,0 +1,363 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+use num_traits::Float;
+
+use crate::csr::CsrGraph;
+use crate::error::Result;
+use crate::ordered_float::OrderedFloat;
+
+/// Bidirectional Dijkstra for single-pair shortest paths
+///
+/// Runs a bounded Dijkstra expansion simultaneously from `source` on the
+/// forward graph and from `target` on the graph's transpose (see
+/// [`CsrGraph::transpose`]), alternating between whichever frontier has the
+/// smaller minimum tentative distance. The search stops once the combined
+/// minimum of the two frontiers can no longer beat the best source-to-target
+/// total seen so far through any vertex reached from both sides, which
+/// typically touches far fewer vertices than a full single-source expansion.
+///
+/// Returns `Ok(None)` if `target` is unreachable from `source`. Otherwise
+/// returns `(distance, path)` where `path` lists vertices from `source` to
+/// `target` inclusive.
+pub fn bidirectional_sssp<T>(
+    graph: &CsrGraph,
+    weights: &[T],
+    source: usize,
+    target: usize,
+    enabled: Option<&[bool]>,
+) -> Result<Option<(T, Vec<usize>)>>
+where
+    T: Float + Copy,
+{
+    if source == target {
+        return Ok(Some((T::zero(), vec![source])));
+    }
+
+    let (rev_graph, edge_map) = graph.transpose();
+    let rev_weights: Vec<T> = edge_map.iter().map(|&e| weights[e]).collect();
+    let rev_enabled: Option<Vec<bool>> =
+        enabled.map(|mask| edge_map.iter().map(|&e| mask[e]).collect());
+
+    bidirectional_core(
+        graph,
+        &rev_graph,
+        weights,
+        &rev_weights,
+        source,
+        target,
+        enabled,
+        rev_enabled.as_deref(),
+    )
+}
+
+/// Bidirectional Dijkstra over a caller-supplied reverse graph
+///
+/// Identical to [`bidirectional_sssp`], except the backward graph and its
+/// edge weights are passed in directly instead of being transposed on
+/// every call. Callers issuing many point-to-point queries against the
+/// same topology (e.g. repeated routing lookups) can build `reverse_graph`
+/// once via [`CsrGraph::transpose`] and reuse it across queries, skipping
+/// the O(n+m) transpose cost each time.
+///
+/// `enabled` is indexed against `graph`'s own edges (same convention as
+/// every other entry point in this crate) and only filters the forward
+/// expansion; `reverse_graph`/`rev_weights` are taken as already
+/// representing whatever edge set the caller wants searched backward.
+pub fn bmssp_bidirectional<T>(
+    graph: &CsrGraph,
+    reverse_graph: &CsrGraph,
+    weights: &[T],
+    rev_weights: &[T],
+    source: usize,
+    target: usize,
+    enabled: Option<&[bool]>,
+) -> Result<Option<(T, Vec<usize>)>>
+where
+    T: Float + Copy,
+{
+    if source == target {
+        return Ok(Some((T::zero(), vec![source])));
+    }
+
+    bidirectional_core(
+        graph,
+        reverse_graph,
+        weights,
+        rev_weights,
+        source,
+        target,
+        enabled,
+        None,
+    )
+}
+
+fn bidirectional_core<T>(
+    graph: &CsrGraph,
+    rev_graph: &CsrGraph,
+    weights: &[T],
+    rev_weights: &[T],
+    source: usize,
+    target: usize,
+    enabled: Option<&[bool]>,
+    rev_enabled: Option<&[bool]>,
+) -> Result<Option<(T, Vec<usize>)>>
+where
+    T: Float + Copy,
+{
+    let n = graph.num_vertices();
+
+    let mut dist_f = vec![T::infinity(); n];
+    let mut dist_b = vec![T::infinity(); n];
+    let mut pred_f = vec![usize::MAX; n];
+    let mut pred_b = vec![usize::MAX; n];
+    dist_f[source] = T::zero();
+    dist_b[target] = T::zero();
+
+    let mut visited_f = vec![false; n];
+    let mut visited_b = vec![false; n];
+
+    let mut heap_f = BinaryHeap::new();
+    let mut heap_b = BinaryHeap::new();
+    heap_f.push(Reverse((OrderedFloat(T::zero()), source)));
+    heap_b.push(Reverse((OrderedFloat(T::zero()), target)));
+
+    let mut best: Option<T> = None;
+    let mut meeting_vertex = usize::MAX;
+
+    loop {
+        let top_f = heap_f.peek().map(|Reverse((OrderedFloat(d), _))| *d);
+        let top_b = heap_b.peek().map(|Reverse((OrderedFloat(d), _))| *d);
+
+        let frontier_bound = match (top_f, top_b) {
+            (Some(f), Some(b)) => Some(f + b),
+            _ => None,
+        };
+        match frontier_bound {
+            Some(bound) => {
+                if let Some(best) = best {
+                    if bound >= best {
+                        break;
+                    }
+                }
+            }
+            None => break,
+        }
+
+        let expand_forward = match (top_f, top_b) {
+            (Some(f), Some(b)) => f <= b,
+            (Some(_), None) => true,
+            (None, Some(_)) => false,
+            (None, None) => break,
+        };
+
+        if expand_forward {
+            let Some(Reverse((OrderedFloat(d), u))) = heap_f.pop() else {
+                break;
+            };
+            if visited_f[u] {
+                continue;
+            }
+            visited_f[u] = true;
+
+            if visited_b[u] {
+                let total = dist_f[u] + dist_b[u];
+                if best.is_none_or(|best| total < best) {
+                    best = Some(total);
+                    meeting_vertex = u;
+                }
+            }
+
+            let (start, _end) = graph.edge_range(u);
+            for (eid, &v) in graph.neighbors(u).iter().enumerate() {
+                let edge_idx = start + eid;
+                if let Some(mask) = enabled {
+                    if !mask[edge_idx] {
+                        continue;
+                    }
+                }
+                let new_dist = d + weights[edge_idx];
+                if new_dist < dist_f[v] {
+                    dist_f[v] = new_dist;
+                    pred_f[v] = u;
+                    heap_f.push(Reverse((OrderedFloat(new_dist), v)));
+                    let total = new_dist + dist_b[v];
+                    if total.is_finite() && best.is_none_or(|best| total < best) {
+                        best = Some(total);
+                        meeting_vertex = v;
+                    }
+                }
+            }
+        } else {
+            let Some(Reverse((OrderedFloat(d), u))) = heap_b.pop() else {
+                break;
+            };
+            if visited_b[u] {
+                continue;
+            }
+            visited_b[u] = true;
+
+            if visited_f[u] {
+                let total = dist_f[u] + dist_b[u];
+                if best.is_none_or(|best| total < best) {
+                    best = Some(total);
+                    meeting_vertex = u;
+                }
+            }
+
+            let (start, _end) = rev_graph.edge_range(u);
+            for (eid, &v) in rev_graph.neighbors(u).iter().enumerate() {
+                let edge_idx = start + eid;
+                if let Some(mask) = rev_enabled {
+                    if !mask[edge_idx] {
+                        continue;
+                    }
+                }
+                let new_dist = d + rev_weights[edge_idx];
+                if new_dist < dist_b[v] {
+                    dist_b[v] = new_dist;
+                    pred_b[v] = u;
+                    heap_b.push(Reverse((OrderedFloat(new_dist), v)));
+                    let total = dist_f[v] + new_dist;
+                    if total.is_finite() && best.is_none_or(|best| total < best) {
+                        best = Some(total);
+                        meeting_vertex = v;
+                    }
+                }
+            }
+        }
+    }
+
+    let Some(total) = best else {
+        return Ok(None);
+    };
+
+    // Walk the forward predecessor chain from the meeting vertex back to
+    // source, then the backward chain from the meeting vertex to target.
+    let mut path = vec![meeting_vertex];
+    let mut cur = meeting_vertex;
+    while cur != source {
+        cur = pred_f[cur];
+        path.push(cur);
+    }
+    path.reverse();
+
+    let mut cur = meeting_vertex;
+    while cur != target {
+        cur = pred_b[cur];
+        path.push(cur);
+    }
+
+    Ok(Some((total, path)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bidirectional_chain() {
+        // 0 -> 1 -> 2 -> 3 (weights 1, 2, 3)
+        let indptr = vec![0, 1, 2, 3, 3];
+        let indices = vec![1, 2, 3];
+        let graph = CsrGraph::new(4, indptr, indices).unwrap();
+        let weights = vec![1.0f32, 2.0, 3.0];
+
+        let (dist, path) = bidirectional_sssp(&graph, &weights, 0, 3, None)
+            .unwrap()
+            .unwrap();
+        assert_eq!(dist, 6.0);
+        assert_eq!(path, vec![0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn test_bidirectional_diamond_picks_shortest() {
+        // 0 -> 1 -> 3 (cost 1+1=2), 0 -> 2 -> 3 (cost 5+1=6)
+        let indptr = vec![0, 2, 3, 4, 4];
+        let indices = vec![1, 2, 3, 3];
+        let graph = CsrGraph::new(4, indptr, indices).unwrap();
+        let weights = vec![1.0f32, 5.0, 1.0, 1.0];
+
+        let (dist, path) = bidirectional_sssp(&graph, &weights, 0, 3, None)
+            .unwrap()
+            .unwrap();
+        assert_eq!(dist, 2.0);
+        assert_eq!(path, vec![0, 1, 3]);
+    }
+
+    #[test]
+    fn test_bidirectional_unreachable() {
+        // 0 -> 1, 2 isolated
+        let indptr = vec![0, 1, 1, 1];
+        let indices = vec![1];
+        let graph = CsrGraph::new(3, indptr, indices).unwrap();
+        let weights = vec![1.0f32];
+
+        let result = bidirectional_sssp(&graph, &weights, 0, 2, None).unwrap();
+        assert!(result.is_none());
+    }
+
+    #[test]
+    fn test_bidirectional_same_source_and_target() {
+        let indptr = vec![0, 1, 1];
+        let indices = vec![1];
+        let graph = CsrGraph::new(2, indptr, indices).unwrap();
+        let weights = vec![1.0f32];
+
+        let (dist, path) = bidirectional_sssp(&graph, &weights, 0, 0, None)
+            .unwrap()
+            .unwrap();
+        assert_eq!(dist, 0.0);
+        assert_eq!(path, vec![0]);
+    }
+
+    #[test]
+    fn test_bidirectional_respects_enabled_mask() {
+        // 0 -> 1 -> 2 direct, plus a disabled shortcut 0 -> 2
+        let indptr = vec![0, 2, 3, 3];
+        let indices = vec![1, 2, 2];
+        let graph = CsrGraph::new(3, indptr, indices).unwrap();
+        let weights = vec![1.0f32, 10.0, 1.0];
+        let enabled = vec![true, false, true];
+
+        let (dist, path) = bidirectional_sssp(&graph, &weights, 0, 2, Some(&enabled))
+            .unwrap()
+            .unwrap();
+        assert_eq!(dist, 2.0);
+        assert_eq!(path, vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn test_bmssp_bidirectional_with_precomputed_reverse_graph() {
+        // 0 -> 1 -> 3 (cost 1+1=2), 0 -> 2 -> 3 (cost 5+1=6)
+        let indptr = vec![0, 2, 3, 4, 4];
+        let indices = vec![1, 2, 3, 3];
+        let graph = CsrGraph::new(4, indptr, indices).unwrap();
+        let weights = vec![1.0f32, 5.0, 1.0, 1.0];
+
+        let (rev_graph, edge_map) = graph.transpose();
+        let rev_weights: Vec<f32> = edge_map.iter().map(|&e| weights[e]).collect();
+
+        let (dist, path) =
+            bmssp_bidirectional(&graph, &rev_graph, &weights, &rev_weights, 0, 3, None)
+                .unwrap()
+                .unwrap();
+        assert_eq!(dist, 2.0);
+        assert_eq!(path, vec![0, 1, 3]);
+    }
+
+    #[test]
+    fn test_bmssp_bidirectional_unreachable() {
+        let indptr = vec![0, 1, 1, 1];
+        let indices = vec![1];
+        let graph = CsrGraph::new(3, indptr, indices).unwrap();
+        let weights = vec![1.0f32];
+
+        let (rev_graph, edge_map) = graph.transpose();
+        let rev_weights: Vec<f32> = edge_map.iter().map(|&e| weights[e]).collect();
+
+        let result =
+            bmssp_bidirectional(&graph, &rev_graph, &weights, &rev_weights, 0, 2, None).unwrap();
+        assert!(result.is_none());
+    }
+}
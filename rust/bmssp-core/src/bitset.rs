@@ -0,0 +1,104 @@
+/// Bit-packed boolean mask backed by `u64` words
+///
+/// Used as a compact alternative to `Vec<bool>` for edge-enabled masks:
+/// one bit per edge instead of one byte, an 8x memory reduction that
+/// matters once a graph has tens of millions of edges. Bit `i` lives in
+/// word `i / 64` at offset `i % 64`.
+#[derive(Debug, Clone)]
+pub struct EnabledMask {
+    words: Vec<u64>,
+    len: usize,
+}
+
+impl EnabledMask {
+    /// A mask of `len` bits, every bit set to `value`
+    pub fn new(len: usize, value: bool) -> Self {
+        let word_count = (len + 63) / 64;
+        let mut words = vec![if value { u64::MAX } else { 0 }; word_count];
+        if value {
+            let tail = len % 64;
+            if tail != 0 {
+                if let Some(last) = words.last_mut() {
+                    *last &= (1u64 << tail) - 1;
+                }
+            }
+        }
+        Self { words, len }
+    }
+
+    /// Pack an existing `&[bool]` mask (e.g. when migrating a caller that
+    /// already built one the old way)
+    pub fn from_bools(mask: &[bool]) -> Self {
+        let mut bits = Self::new(mask.len(), false);
+        for (i, &enabled) in mask.iter().enumerate() {
+            if enabled {
+                bits.set(i, true);
+            }
+        }
+        bits
+    }
+
+    /// Number of bits in the mask
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether the mask covers zero bits
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Read bit `index`
+    #[inline]
+    pub fn get(&self, index: usize) -> bool {
+        (self.words[index / 64] >> (index % 64)) & 1 != 0
+    }
+
+    /// Set bit `index` to `value`
+    #[inline]
+    pub fn set(&mut self, index: usize, value: bool) {
+        let word = &mut self.words[index / 64];
+        let bit = 1u64 << (index % 64);
+        if value {
+            *word |= bit;
+        } else {
+            *word &= !bit;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_enabled_mask_new_all_set_matches_bool_slice() {
+        let bits = EnabledMask::new(5, true);
+        for i in 0..5 {
+            assert!(bits.get(i));
+        }
+    }
+
+    #[test]
+    fn test_enabled_mask_from_bools_round_trips() {
+        let mask = vec![true, false, true, true, false, false, true];
+        let bits = EnabledMask::from_bools(&mask);
+        assert_eq!(bits.len(), mask.len());
+        for (i, &expected) in mask.iter().enumerate() {
+            assert_eq!(bits.get(i), expected);
+        }
+    }
+
+    #[test]
+    fn test_enabled_mask_set_flips_single_bit_across_word_boundary() {
+        let mut bits = EnabledMask::new(70, false);
+        bits.set(65, true);
+        assert!(bits.get(65));
+        assert!(!bits.get(64));
+        assert!(!bits.get(66));
+        bits.set(65, false);
+        assert!(!bits.get(65));
+    }
+}
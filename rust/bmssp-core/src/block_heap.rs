@@ -1,9 +1,103 @@
 use std::collections::{BinaryHeap, HashMap};
+use std::marker::PhantomData;
 use num_traits::Float;
 use crate::ordered_float::OrderedFloat;
+use crate::quantile::GkSummary;
 
 const DEFAULT_BLOCK_BYTES: usize = 4096;
 
+/// Common interface for the float-keyed BMSSP frontier heaps
+///
+/// [`BlockHeap`], [`FastBlockHeap`], [`IndexedBlockHeap`], and
+/// [`PairingBlockHeap`] each make a different tradeoff between decrease-key
+/// cost, memory, and implementation complexity, but BMSSP only ever drives
+/// them through this surface. Implementing it lets a caller pick a backend
+/// at runtime (see [`FrontierKind`]) or write algorithm code generic over
+/// `F: Frontier<T>` instead of hard-coding one backend. [`BucketHeap`] is
+/// deliberately not included: its integer-bucketed distances make it a
+/// different (non-`Float`) kind of frontier, not a drop-in substitute.
+pub trait Frontier<T: Float> {
+    /// Add or update a vertex with a distance, regardless of whether it's
+    /// an improvement
+    fn push(&mut self, vertex: usize, distance: T);
+    /// Decrease the distance for a vertex (if the new distance is smaller),
+    /// inserting it if it isn't tracked yet
+    fn decrease_key(&mut self, vertex: usize, new_distance: T);
+    /// Pop a block of up to `max_size` vertices with smallest distances,
+    /// returning them ordered by distance plus the next distance
+    /// threshold (b_next) if the frontier is not empty
+    fn pop_block(&mut self, max_size: usize) -> (Vec<(usize, T)>, Option<T>);
+    /// Check if the frontier is empty
+    fn is_empty(&self) -> bool;
+    /// Get the minimum distance in the frontier (if any)
+    fn min_distance(&self) -> Option<T>;
+
+    /// Consume the frontier, returning every tracked vertex in ascending
+    /// distance order, mirroring `std::collections::BinaryHeap::into_sorted_vec`
+    fn into_sorted_vec(mut self) -> Vec<(usize, T)>
+    where
+        Self: Sized,
+    {
+        self.pop_block(usize::MAX).0
+    }
+
+    /// Drain the frontier one vertex at a time in ascending distance order,
+    /// mirroring `std::collections::BinaryHeap::drain_sorted`
+    fn drain_sorted(&mut self) -> DrainSorted<'_, T, Self>
+    where
+        Self: Sized,
+    {
+        DrainSorted {
+            frontier: self,
+            _marker: PhantomData,
+        }
+    }
+}
+
+/// Iterator returned by [`Frontier::drain_sorted`]; yields vertices in
+/// ascending distance order, one `pop_block(1)` at a time
+pub struct DrainSorted<'a, T: Float, F: Frontier<T>> {
+    frontier: &'a mut F,
+    _marker: PhantomData<T>,
+}
+
+impl<'a, T, F> Iterator for DrainSorted<'a, T, F>
+where
+    T: Float,
+    F: Frontier<T>,
+{
+    type Item = (usize, T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.frontier.pop_block(1).0.into_iter().next()
+    }
+}
+
+/// Forwarding impl so a boxed frontier (e.g. from [`FrontierKind::build`])
+/// can be used anywhere a `Frontier<T>` is expected, including the
+/// `Self: Sized` default methods like `into_sorted_vec`/`drain_sorted`
+impl<T, F> Frontier<T> for Box<F>
+where
+    T: Float,
+    F: Frontier<T> + ?Sized,
+{
+    fn push(&mut self, vertex: usize, distance: T) {
+        (**self).push(vertex, distance)
+    }
+    fn decrease_key(&mut self, vertex: usize, new_distance: T) {
+        (**self).decrease_key(vertex, new_distance)
+    }
+    fn pop_block(&mut self, max_size: usize) -> (Vec<(usize, T)>, Option<T>) {
+        (**self).pop_block(max_size)
+    }
+    fn is_empty(&self) -> bool {
+        (**self).is_empty()
+    }
+    fn min_distance(&self) -> Option<T> {
+        (**self).min_distance()
+    }
+}
+
 #[derive(Debug)]
 struct Block<T> {
     entries: Vec<(OrderedFloat<T>, usize)>,
@@ -49,6 +143,60 @@ where
         }
     }
 
+    /// Bulk-build a block heap from `(vertex, distance)` pairs in O(n log n)
+    ///
+    /// Sorts the pairs once (keeping the smallest distance per vertex) and
+    /// chunks the result directly into `block_capacity`-sized blocks,
+    /// instead of paying the binary-search-and-possibly-split cost of
+    /// [`BlockHeap::push`] once per entry.
+    pub fn from_vec(entries: Vec<(usize, T)>) -> Self {
+        let mut heap = Self::new();
+        if entries.is_empty() {
+            return heap;
+        }
+
+        let mut best: HashMap<usize, T> = HashMap::with_capacity(entries.len());
+        for (vertex, distance) in entries {
+            best.entry(vertex)
+                .and_modify(|d| {
+                    if distance < *d {
+                        *d = distance;
+                    }
+                })
+                .or_insert(distance);
+        }
+
+        let mut sorted: Vec<(OrderedFloat<T>, usize)> = best
+            .into_iter()
+            .map(|(vertex, distance)| (OrderedFloat(distance), vertex))
+            .collect();
+        sorted.sort_unstable_by(|a, b| a.cmp(b));
+
+        for chunk in sorted.chunks(heap.block_capacity) {
+            heap.blocks.push(Block {
+                entries: chunk.to_vec(),
+            });
+        }
+        for (block_index, block) in heap.blocks.iter().enumerate() {
+            for (entry_index, (OrderedFloat(distance), vertex)) in block.entries.iter().enumerate() {
+                heap.locations.insert(
+                    *vertex,
+                    EntryInfo {
+                        distance: *distance,
+                        block_index,
+                        entry_index,
+                    },
+                );
+            }
+        }
+        heap
+    }
+
+    /// Bulk-build a block heap from an iterator of `(vertex, distance)` pairs
+    pub fn from_iter<I: IntoIterator<Item = (usize, T)>>(entries: I) -> Self {
+        Self::from_vec(entries.into_iter().collect())
+    }
+
     /// Add or update a vertex with a distance
     pub fn push(&mut self, vertex: usize, distance: T) {
         self.remove_vertex(vertex);
@@ -99,11 +247,74 @@ where
         (block, b_next)
     }
 
+    /// Pop an approximate block: all vertices with distance at or below the
+    /// `phi`-quantile of the current frontier, found via an
+    /// epsilon-approximate rank summary ([`GkSummary`]) instead of a full
+    /// sort. This is opt-in; [`BlockHeap::pop_block`] remains the exact,
+    /// default extraction method.
+    ///
+    /// Returns the extracted vertices (ordered by distance) and the next
+    /// distance threshold (b_next), same as `pop_block`.
+    pub fn pop_approx_block(&mut self, phi: f64, epsilon: f64) -> (Vec<(usize, T)>, Option<T>) {
+        let mut summary = GkSummary::new(epsilon);
+        for block in &self.blocks {
+            for (OrderedFloat(dist), _) in &block.entries {
+                summary.insert(*dist);
+            }
+        }
+
+        let Some(threshold) = summary.quantile(phi) else {
+            return (Vec::new(), None);
+        };
+
+        let mut block = Vec::new();
+        loop {
+            let Some(first_block) = self.blocks.first() else {
+                break;
+            };
+            let take = first_block
+                .entries
+                .partition_point(|(OrderedFloat(dist), _)| *dist <= threshold);
+            if take == 0 {
+                break;
+            }
+
+            let drained: Vec<(OrderedFloat<T>, usize)> =
+                self.blocks[0].entries.drain(0..take).collect();
+            let fully_drained = self.blocks[0].entries.is_empty();
+            for (OrderedFloat(dist), vertex) in drained {
+                self.locations.remove(&vertex);
+                block.push((vertex, dist));
+            }
+
+            if fully_drained {
+                self.blocks.remove(0);
+                self.refresh_locations_from(0);
+            } else {
+                self.refresh_block_locations(0);
+                break;
+            }
+        }
+
+        let b_next = self
+            .blocks
+            .first()
+            .and_then(|block| block.entries.first())
+            .map(|(OrderedFloat(dist), _)| *dist);
+
+        (block, b_next)
+    }
+
     /// Check if the heap is empty
     pub fn is_empty(&self) -> bool {
         self.blocks.is_empty()
     }
 
+    /// Total number of vertices currently tracked by the heap
+    pub fn len(&self) -> usize {
+        self.locations.len()
+    }
+
     /// Get the minimum distance in the heap (if any)
     pub fn min_distance(&self) -> Option<T> {
         self.blocks
@@ -179,37 +390,637 @@ where
         }
     }
 
-    fn refresh_block_locations(&mut self, block_index: usize) {
-        if block_index >= self.blocks.len() {
+    fn refresh_block_locations(&mut self, block_index: usize) {
+        if block_index >= self.blocks.len() {
+            return;
+        }
+        for (entry_index, (_, vertex)) in self.blocks[block_index].entries.iter().enumerate() {
+            let (OrderedFloat(distance), _) = self.blocks[block_index].entries[entry_index];
+            if let Some(info) = self.locations.get_mut(vertex) {
+                info.distance = distance;
+                info.block_index = block_index;
+                info.entry_index = entry_index;
+            } else {
+                self.locations.insert(
+                    *vertex,
+                    EntryInfo {
+                        distance,
+                        block_index,
+                        entry_index,
+                    },
+                );
+            }
+        }
+    }
+
+    fn refresh_locations_from(&mut self, start_index: usize) {
+        for index in start_index..self.blocks.len() {
+            self.refresh_block_locations(index);
+        }
+    }
+}
+
+impl<T> Default for BlockHeap<T>
+where
+    T: Float + Copy,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Frontier<T> for BlockHeap<T>
+where
+    T: Float + Copy,
+{
+    fn push(&mut self, vertex: usize, distance: T) {
+        BlockHeap::push(self, vertex, distance)
+    }
+    fn decrease_key(&mut self, vertex: usize, new_distance: T) {
+        BlockHeap::decrease_key(self, vertex, new_distance)
+    }
+    fn pop_block(&mut self, max_size: usize) -> (Vec<(usize, T)>, Option<T>) {
+        BlockHeap::pop_block(self, max_size)
+    }
+    fn is_empty(&self) -> bool {
+        BlockHeap::is_empty(self)
+    }
+    fn min_distance(&self) -> Option<T> {
+        BlockHeap::min_distance(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_push_pop() {
+        let mut heap = BlockHeap::new();
+        heap.push(0, 1.0f32);
+        heap.push(1, 2.0f32);
+        
+        let (block, _) = heap.pop_block(2);
+        assert_eq!(block.len(), 2);
+        assert_eq!(block[0].0, 0); // Vertex 0 has smaller distance
+        assert_eq!(block[0].1, 1.0);
+    }
+
+    #[test]
+    fn test_decrease_key() {
+        let mut heap = BlockHeap::new();
+        heap.push(0, 5.0f32);
+        heap.decrease_key(0, 2.0f32);
+
+        let (block, _) = heap.pop_block(1);
+        assert_eq!(block[0].1, 2.0);
+    }
+
+    #[test]
+    fn test_from_vec_matches_incremental_push() {
+        let pairs = vec![(3usize, 3.0f32), (1, 1.0), (2, 2.0), (0, 0.0)];
+
+        let mut pushed = BlockHeap::new();
+        for &(v, d) in &pairs {
+            pushed.push(v, d);
+        }
+        let mut built = BlockHeap::from_vec(pairs);
+
+        let mut pushed_out = Vec::new();
+        let mut built_out = Vec::new();
+        while !pushed.is_empty() {
+            pushed_out.push(pushed.pop_block(1).0[0]);
+        }
+        while !built.is_empty() {
+            built_out.push(built.pop_block(1).0[0]);
+        }
+        assert_eq!(pushed_out, built_out);
+    }
+
+    #[test]
+    fn test_from_vec_keeps_smallest_distance_for_duplicate_vertex() {
+        let heap = BlockHeap::from_vec(vec![(0usize, 5.0f32), (0, 1.0), (0, 3.0)]);
+        assert_eq!(heap.len(), 1);
+        assert_eq!(heap.min_distance(), Some(1.0));
+    }
+
+    #[test]
+    fn test_pop_approx_block_half() {
+        let mut heap = BlockHeap::new();
+        for i in 0..10 {
+            heap.push(i, i as f32);
+        }
+        assert_eq!(heap.len(), 10);
+
+        let (block, _) = heap.pop_approx_block(0.5, 0.01);
+        // Tight epsilon should land close to the true median split
+        assert!(block.len() >= 4 && block.len() <= 7);
+        for (_, d) in &block {
+            assert!(*d <= 6.0);
+        }
+    }
+
+    #[test]
+    fn test_pop_approx_block_empty() {
+        let mut heap = BlockHeap::<f32>::new();
+        let (block, b_next) = heap.pop_approx_block(0.5, 0.01);
+        assert!(block.is_empty());
+        assert!(b_next.is_none());
+    }
+
+    #[test]
+    fn test_pop_approx_block_full() {
+        let mut heap = BlockHeap::new();
+        for i in 0..5 {
+            heap.push(i, i as f32);
+        }
+        let (block, b_next) = heap.pop_approx_block(1.0, 0.01);
+        assert_eq!(block.len(), 5);
+        assert!(heap.is_empty());
+        assert!(b_next.is_none());
+    }
+}
+
+/// Indexed binary heap with true O(log n) decrease-key and no stale entries
+///
+/// Unlike [`BlockHeap`] (O(block) remove+reinsert per decrease-key) and
+/// [`FastBlockHeap`] (lazy deletion that leaks stale entries until the next
+/// full re-heapify), this keeps a single binary-heap-ordered `Vec<(distance,
+/// vertex)>` plus a `HashMap<usize, usize>` from vertex to its current slot
+/// in that vec, so a decrease-key can locate its entry directly and sift it
+/// up in place — every swap updates both entries' positions in the map, so
+/// the map and the heap never drift apart.
+pub struct IndexedBlockHeap<T> {
+    heap: Vec<(OrderedFloat<T>, usize)>,
+    positions: HashMap<usize, usize>,
+}
+
+impl<T> IndexedBlockHeap<T>
+where
+    T: Float + Copy,
+{
+    /// Create a new empty indexed block heap
+    pub fn new() -> Self {
+        Self {
+            heap: Vec::new(),
+            positions: HashMap::new(),
+        }
+    }
+
+    /// Add or update a vertex with a distance, regardless of whether it's
+    /// an improvement (matching [`BlockHeap::push`]/[`FastBlockHeap::push`])
+    pub fn push(&mut self, vertex: usize, distance: T) {
+        if let Some(&idx) = self.positions.get(&vertex) {
+            let OrderedFloat(current) = self.heap[idx].0;
+            self.heap[idx].0 = OrderedFloat(distance);
+            if distance < current {
+                self.sift_up(idx);
+            } else if distance > current {
+                self.sift_down(idx);
+            }
+            return;
+        }
+        self.heap.push((OrderedFloat(distance), vertex));
+        let idx = self.heap.len() - 1;
+        self.positions.insert(vertex, idx);
+        self.sift_up(idx);
+    }
+
+    /// Decrease the distance for a vertex (if the new distance is smaller),
+    /// inserting it if it isn't tracked yet
+    pub fn decrease_key(&mut self, vertex: usize, new_distance: T) {
+        if let Some(&idx) = self.positions.get(&vertex) {
+            let OrderedFloat(current) = self.heap[idx].0;
+            if new_distance < current {
+                self.heap[idx].0 = OrderedFloat(new_distance);
+                self.sift_up(idx);
+            }
+            return;
+        }
+        self.push(vertex, new_distance);
+    }
+
+    /// Pop a block of up to `max_size` vertices with smallest distances
+    ///
+    /// Returns the vertices and their distances, ordered by distance.
+    /// Also returns the next distance threshold (b_next) if the heap is
+    /// not empty.
+    pub fn pop_block(&mut self, max_size: usize) -> (Vec<(usize, T)>, Option<T>) {
+        let mut block = Vec::with_capacity(max_size.min(self.heap.len()));
+        while block.len() < max_size {
+            match self.pop_min() {
+                Some(entry) => block.push(entry),
+                None => break,
+            }
+        }
+        let b_next = self.min_distance();
+        (block, b_next)
+    }
+
+    /// Check if the heap is empty
+    pub fn is_empty(&self) -> bool {
+        self.heap.is_empty()
+    }
+
+    /// Total number of vertices currently tracked by the heap
+    pub fn len(&self) -> usize {
+        self.heap.len()
+    }
+
+    /// Get the minimum distance in the heap (if any)
+    pub fn min_distance(&self) -> Option<T> {
+        self.heap.first().map(|(OrderedFloat(dist), _)| *dist)
+    }
+
+    fn pop_min(&mut self) -> Option<(usize, T)> {
+        if self.heap.is_empty() {
+            return None;
+        }
+        let last = self.heap.len() - 1;
+        self.swap_entries(0, last);
+        let (OrderedFloat(dist), vertex) = self.heap.pop().expect("heap is non-empty");
+        self.positions.remove(&vertex);
+        if !self.heap.is_empty() {
+            self.sift_down(0);
+        }
+        Some((vertex, dist))
+    }
+
+    fn swap_entries(&mut self, i: usize, j: usize) {
+        self.heap.swap(i, j);
+        self.positions.insert(self.heap[i].1, i);
+        self.positions.insert(self.heap[j].1, j);
+    }
+
+    fn sift_up(&mut self, mut idx: usize) {
+        while idx > 0 {
+            let parent = (idx - 1) / 2;
+            if self.heap[idx].0 < self.heap[parent].0 {
+                self.swap_entries(idx, parent);
+                idx = parent;
+            } else {
+                break;
+            }
+        }
+    }
+
+    fn sift_down(&mut self, mut idx: usize) {
+        let len = self.heap.len();
+        loop {
+            let left = 2 * idx + 1;
+            let right = 2 * idx + 2;
+            let mut smallest = idx;
+            if left < len && self.heap[left].0 < self.heap[smallest].0 {
+                smallest = left;
+            }
+            if right < len && self.heap[right].0 < self.heap[smallest].0 {
+                smallest = right;
+            }
+            if smallest == idx {
+                break;
+            }
+            self.swap_entries(idx, smallest);
+            idx = smallest;
+        }
+    }
+}
+
+impl<T> Default for IndexedBlockHeap<T>
+where
+    T: Float + Copy,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Frontier<T> for IndexedBlockHeap<T>
+where
+    T: Float + Copy,
+{
+    fn push(&mut self, vertex: usize, distance: T) {
+        IndexedBlockHeap::push(self, vertex, distance)
+    }
+    fn decrease_key(&mut self, vertex: usize, new_distance: T) {
+        IndexedBlockHeap::decrease_key(self, vertex, new_distance)
+    }
+    fn pop_block(&mut self, max_size: usize) -> (Vec<(usize, T)>, Option<T>) {
+        IndexedBlockHeap::pop_block(self, max_size)
+    }
+    fn is_empty(&self) -> bool {
+        IndexedBlockHeap::is_empty(self)
+    }
+    fn min_distance(&self) -> Option<T> {
+        IndexedBlockHeap::min_distance(self)
+    }
+}
+
+#[cfg(test)]
+mod indexed_block_heap_tests {
+    use super::*;
+
+    #[test]
+    fn test_indexed_push_pop_order() {
+        let mut heap = IndexedBlockHeap::new();
+        heap.push(0, 3.0f32);
+        heap.push(1, 1.0f32);
+        heap.push(2, 2.0f32);
+
+        let (block, _) = heap.pop_block(3);
+        assert_eq!(block, vec![(1, 1.0), (2, 2.0), (0, 3.0)]);
+        assert!(heap.is_empty());
+    }
+
+    #[test]
+    fn test_indexed_decrease_key_sifts_up() {
+        let mut heap = IndexedBlockHeap::new();
+        heap.push(0, 5.0f32);
+        heap.push(1, 1.0f32);
+        heap.decrease_key(0, 0.5f32);
+
+        let (block, _) = heap.pop_block(1);
+        assert_eq!(block[0], (0, 0.5));
+    }
+
+    #[test]
+    fn test_indexed_decrease_key_ignores_increase() {
+        let mut heap = IndexedBlockHeap::new();
+        heap.push(0, 1.0f32);
+        heap.decrease_key(0, 5.0f32); // not a decrease, ignored
+
+        assert_eq!(heap.min_distance(), Some(1.0));
+    }
+
+    #[test]
+    fn test_indexed_heap_matches_other_backends_on_random_order() {
+        let mut indexed = IndexedBlockHeap::new();
+        let mut fast = FastBlockHeap::new();
+        let mut block = BlockHeap::new();
+
+        let order = [5usize, 1, 3, 4, 2, 0, 7, 6];
+        for &v in &order {
+            let d = v as f32 * 1.5;
+            indexed.push(v, d);
+            fast.push(v, d);
+            block.push(v, d);
+        }
+        indexed.decrease_key(7, 0.1);
+        fast.decrease_key(7, 0.1);
+        block.decrease_key(7, 0.1);
+
+        let mut indexed_out = Vec::new();
+        let mut fast_out = Vec::new();
+        let mut block_out = Vec::new();
+        while !indexed.is_empty() {
+            indexed_out.push(indexed.pop_block(1).0[0]);
+        }
+        while !fast.is_empty() {
+            fast_out.push(fast.pop_block(1).0[0]);
+        }
+        while !block.is_empty() {
+            block_out.push(block.pop_block(1).0[0]);
+        }
+
+        assert_eq!(indexed_out, fast_out);
+        assert_eq!(indexed_out, block_out);
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct PairingNode<T> {
+    vertex: usize,
+    distance: T,
+    parent: Option<usize>,
+    child: Option<usize>,
+    sibling: Option<usize>,
+}
+
+/// Pairing heap frontier with O(1) amortized decrease-key
+///
+/// Nodes live in an arena (`Vec<PairingNode<T>>`) linked by child/sibling/
+/// parent indices, with a `HashMap<usize, usize>` from vertex to its arena
+/// slot. `push` of a new vertex melds a singleton onto the root, and
+/// `decrease_key` cuts the node and melds its subtree back onto the root
+/// (the rare increase-key case in [`PairingBlockHeap::push`] instead deletes
+/// the node and inserts a fresh singleton). Melding goes through
+/// [`PairingBlockHeap::meld`], which makes the larger-key root the
+/// first child of the smaller-key root — O(1) worst case. `pop_block`
+/// repeatedly removes the root and combines its children with the standard
+/// two-pass (pair left-to-right, then fold right-to-left) merge, which is
+/// what gives the amortized O(log n) extract-min / O(1) decrease-key
+/// bounds. This is the pairing-heap backend the trailing comment on
+/// [`FastBlockHeap`] flagged as deliberately deferred.
+pub struct PairingBlockHeap<T> {
+    arena: Vec<PairingNode<T>>,
+    root: Option<usize>,
+    positions: HashMap<usize, usize>,
+    count: usize,
+}
+
+impl<T> PairingBlockHeap<T>
+where
+    T: Float + Copy,
+{
+    /// Create a new empty pairing heap
+    pub fn new() -> Self {
+        Self {
+            arena: Vec::new(),
+            root: None,
+            positions: HashMap::new(),
+            count: 0,
+        }
+    }
+
+    /// Add or update a vertex with a distance, regardless of whether it's
+    /// an improvement (matching [`BlockHeap::push`]/[`FastBlockHeap::push`])
+    pub fn push(&mut self, vertex: usize, distance: T) {
+        if let Some(&idx) = self.positions.get(&vertex) {
+            let current = self.arena[idx].distance;
+            if distance < current {
+                self.decrease_key(vertex, distance);
+            } else if distance > current {
+                // Increasing a key can violate the subtree's heap order, so
+                // fall back to delete (merging its children back into the
+                // heap) plus a fresh insert.
+                self.delete_node(idx);
+                self.insert_new(vertex, distance);
+            }
+            return;
+        }
+        self.insert_new(vertex, distance);
+    }
+
+    /// Decrease the distance for a vertex (if new distance is smaller),
+    /// inserting it if it isn't tracked yet
+    pub fn decrease_key(&mut self, vertex: usize, new_distance: T) {
+        let Some(&idx) = self.positions.get(&vertex) else {
+            self.insert_new(vertex, new_distance);
+            return;
+        };
+        if new_distance >= self.arena[idx].distance {
+            return;
+        }
+        self.arena[idx].distance = new_distance;
+        if self.root == Some(idx) {
+            return;
+        }
+        self.cut(idx);
+        self.root = Some(match self.root {
+            None => idx,
+            Some(r) => self.meld(r, idx),
+        });
+    }
+
+    /// Pop a block of up to `max_size` vertices with smallest distances
+    ///
+    /// Returns the vertices and their distances, ordered by distance.
+    /// Also returns the next distance threshold (b_next) if the heap is
+    /// not empty.
+    pub fn pop_block(&mut self, max_size: usize) -> (Vec<(usize, T)>, Option<T>) {
+        let mut block = Vec::with_capacity(max_size.min(self.count));
+        while block.len() < max_size {
+            match self.pop_min() {
+                Some(entry) => block.push(entry),
+                None => break,
+            }
+        }
+        let b_next = self.min_distance();
+        (block, b_next)
+    }
+
+    /// Check if the heap is empty
+    pub fn is_empty(&self) -> bool {
+        self.root.is_none()
+    }
+
+    /// Total number of vertices currently tracked by the heap
+    pub fn len(&self) -> usize {
+        self.count
+    }
+
+    /// Get the minimum distance in the heap (if any)
+    pub fn min_distance(&self) -> Option<T> {
+        self.root.map(|r| self.arena[r].distance)
+    }
+
+    fn insert_new(&mut self, vertex: usize, distance: T) {
+        let idx = self.arena.len();
+        self.arena.push(PairingNode {
+            vertex,
+            distance,
+            parent: None,
+            child: None,
+            sibling: None,
+        });
+        self.positions.insert(vertex, idx);
+        self.count += 1;
+        self.root = Some(match self.root {
+            None => idx,
+            Some(r) => self.meld(r, idx),
+        });
+    }
+
+    fn pop_min(&mut self) -> Option<(usize, T)> {
+        let root = self.root?;
+        Some(self.delete_node(root))
+    }
+
+    /// Remove `idx` entirely from the heap: detach it from its parent (or
+    /// clear the root), two-pass-merge its children back in, and return the
+    /// vertex/distance it held
+    fn delete_node(&mut self, idx: usize) -> (usize, T) {
+        if self.root == Some(idx) {
+            self.root = None;
+        } else {
+            self.cut(idx);
+        }
+
+        let mut children = Vec::new();
+        let mut cur = self.arena[idx].child;
+        while let Some(c) = cur {
+            let next = self.arena[c].sibling;
+            self.arena[c].parent = None;
+            self.arena[c].sibling = None;
+            children.push(c);
+            cur = next;
+        }
+        self.arena[idx].child = None;
+
+        let merged_children = self.merge_pairs(children);
+        self.root = match (self.root, merged_children) {
+            (None, Some(c)) => Some(c),
+            (Some(r), Some(c)) => Some(self.meld(r, c)),
+            (root, None) => root,
+        };
+
+        let vertex = self.arena[idx].vertex;
+        let distance = self.arena[idx].distance;
+        self.positions.remove(&vertex);
+        self.count -= 1;
+        (vertex, distance)
+    }
+
+    /// Cut `idx` out of its parent's child list; no-op if `idx` is a root
+    fn cut(&mut self, idx: usize) {
+        let Some(parent) = self.arena[idx].parent else {
             return;
-        }
-        for (entry_index, (_, vertex)) in self.blocks[block_index].entries.iter().enumerate() {
-            let (OrderedFloat(distance), _) = self.blocks[block_index].entries[entry_index];
-            if let Some(info) = self.locations.get_mut(vertex) {
-                info.distance = distance;
-                info.block_index = block_index;
-                info.entry_index = entry_index;
-            } else {
-                self.locations.insert(
-                    *vertex,
-                    EntryInfo {
-                        distance,
-                        block_index,
-                        entry_index,
-                    },
-                );
+        };
+        if self.arena[parent].child == Some(idx) {
+            self.arena[parent].child = self.arena[idx].sibling;
+        } else {
+            let mut cur = self.arena[parent].child;
+            while let Some(c) = cur {
+                if self.arena[c].sibling == Some(idx) {
+                    self.arena[c].sibling = self.arena[idx].sibling;
+                    break;
+                }
+                cur = self.arena[c].sibling;
             }
         }
+        self.arena[idx].parent = None;
+        self.arena[idx].sibling = None;
     }
 
-    fn refresh_locations_from(&mut self, start_index: usize) {
-        for index in start_index..self.blocks.len() {
-            self.refresh_block_locations(index);
+    /// Meld two root trees: the smaller-distance root wins and the other
+    /// becomes its new first child. Returns the winning root's index.
+    fn meld(&mut self, a: usize, b: usize) -> usize {
+        let (winner, loser) = if self.arena[a].distance <= self.arena[b].distance {
+            (a, b)
+        } else {
+            (b, a)
+        };
+        let old_child = self.arena[winner].child;
+        self.arena[loser].sibling = old_child;
+        self.arena[loser].parent = Some(winner);
+        self.arena[winner].child = Some(loser);
+        winner
+    }
+
+    /// Standard two-pass pairing-heap merge: pair siblings left to right,
+    /// then fold the resulting list right to left
+    fn merge_pairs(&mut self, nodes: Vec<usize>) -> Option<usize> {
+        if nodes.is_empty() {
+            return None;
+        }
+        let mut paired = Vec::with_capacity(nodes.len().div_ceil(2));
+        let mut i = 0;
+        while i + 1 < nodes.len() {
+            paired.push(self.meld(nodes[i], nodes[i + 1]));
+            i += 2;
+        }
+        if i < nodes.len() {
+            paired.push(nodes[i]);
+        }
+
+        let mut result = paired.pop().expect("at least one node");
+        while let Some(next) = paired.pop() {
+            result = self.meld(result, next);
         }
+        Some(result)
     }
 }
 
-impl<T> Default for BlockHeap<T>
+impl<T> Default for PairingBlockHeap<T>
 where
     T: Float + Copy,
 {
@@ -218,30 +1029,97 @@ where
     }
 }
 
+impl<T> Frontier<T> for PairingBlockHeap<T>
+where
+    T: Float + Copy,
+{
+    fn push(&mut self, vertex: usize, distance: T) {
+        PairingBlockHeap::push(self, vertex, distance)
+    }
+    fn decrease_key(&mut self, vertex: usize, new_distance: T) {
+        PairingBlockHeap::decrease_key(self, vertex, new_distance)
+    }
+    fn pop_block(&mut self, max_size: usize) -> (Vec<(usize, T)>, Option<T>) {
+        PairingBlockHeap::pop_block(self, max_size)
+    }
+    fn is_empty(&self) -> bool {
+        PairingBlockHeap::is_empty(self)
+    }
+    fn min_distance(&self) -> Option<T> {
+        PairingBlockHeap::min_distance(self)
+    }
+}
+
 #[cfg(test)]
-mod tests {
+mod pairing_block_heap_tests {
     use super::*;
 
     #[test]
-    fn test_push_pop() {
-        let mut heap = BlockHeap::new();
+    fn test_pairing_push_pop_order() {
+        let mut heap = PairingBlockHeap::new();
+        heap.push(0, 3.0f32);
+        heap.push(1, 1.0f32);
+        heap.push(2, 2.0f32);
+
+        let (block, _) = heap.pop_block(3);
+        assert_eq!(block, vec![(1, 1.0), (2, 2.0), (0, 3.0)]);
+        assert!(heap.is_empty());
+    }
+
+    #[test]
+    fn test_pairing_decrease_key_promotes_to_root() {
+        let mut heap = PairingBlockHeap::new();
+        heap.push(0, 5.0f32);
+        heap.push(1, 1.0f32);
+        heap.decrease_key(0, 0.5f32);
+
+        let (block, _) = heap.pop_block(1);
+        assert_eq!(block[0], (0, 0.5));
+    }
+
+    #[test]
+    fn test_pairing_push_handles_increase_key() {
+        let mut heap = PairingBlockHeap::new();
         heap.push(0, 1.0f32);
         heap.push(1, 2.0f32);
-        
+        heap.push(0, 5.0f32); // increase: 0 should now sort after 1
+
         let (block, _) = heap.pop_block(2);
-        assert_eq!(block.len(), 2);
-        assert_eq!(block[0].0, 0); // Vertex 0 has smaller distance
-        assert_eq!(block[0].1, 1.0);
+        assert_eq!(block, vec![(1, 2.0), (0, 5.0)]);
     }
 
     #[test]
-    fn test_decrease_key() {
-        let mut heap = BlockHeap::new();
-        heap.push(0, 5.0f32);
-        heap.decrease_key(0, 2.0f32);
-        
-        let (block, _) = heap.pop_block(1);
-        assert_eq!(block[0].1, 2.0);
+    fn test_pairing_heap_matches_other_backends_on_random_order() {
+        let mut pairing = PairingBlockHeap::new();
+        let mut indexed = IndexedBlockHeap::new();
+        let mut fast = FastBlockHeap::new();
+
+        let order = [5usize, 1, 3, 4, 2, 0, 7, 6];
+        for &v in &order {
+            let d = v as f32 * 1.5;
+            pairing.push(v, d);
+            indexed.push(v, d);
+            fast.push(v, d);
+        }
+        pairing.decrease_key(7, 0.1);
+        indexed.decrease_key(7, 0.1);
+        fast.decrease_key(7, 0.1);
+
+        let mut pairing_out = Vec::new();
+        let mut indexed_out = Vec::new();
+        let mut fast_out = Vec::new();
+        while !pairing.is_empty() {
+            pairing_out.push(pairing.pop_block(1).0[0]);
+        }
+        while !indexed.is_empty() {
+            indexed_out.push(indexed.pop_block(1).0[0]);
+        }
+        while !fast.is_empty() {
+            fast_out.push(fast.pop_block(1).0[0]);
+        }
+
+        assert_eq!(pairing_out, indexed_out);
+        assert_eq!(pairing_out, fast_out);
     }
 }
 
@@ -258,6 +1136,11 @@ pub struct FastBlockHeap<T> {
     heap: BinaryHeap<(OrderedFloat<T>, usize)>,
     /// Map from vertex to current distance (for detecting stale entries)
     distances: HashMap<usize, T>,
+    /// Count of entries in `heap` known to be stale (superseded by a later
+    /// push/decrease_key for the same vertex). Once this exceeds half the
+    /// heap's size, [`FastBlockHeap::push`] triggers an incremental
+    /// compaction instead of letting dead weight accumulate unbounded.
+    stale: usize,
 }
 
 impl<T> FastBlockHeap<T>
@@ -269,19 +1152,60 @@ where
         Self {
             heap: BinaryHeap::new(),
             distances: HashMap::new(),
+            stale: 0,
+        }
+    }
+
+    /// Bulk-build a fast block heap from `(vertex, distance)` pairs in O(n)
+    ///
+    /// Dumps the pairs straight into the backing vec (keeping the smallest
+    /// distance per vertex), then builds the binary heap in one bottom-up
+    /// heapify instead of paying for `n` individual `push` calls.
+    pub fn from_vec(entries: Vec<(usize, T)>) -> Self {
+        let mut distances: HashMap<usize, T> = HashMap::with_capacity(entries.len());
+        for (vertex, distance) in entries {
+            distances
+                .entry(vertex)
+                .and_modify(|d| {
+                    if distance < *d {
+                        *d = distance;
+                    }
+                })
+                .or_insert(distance);
+        }
+
+        let heap_vec: Vec<(OrderedFloat<T>, usize)> = distances
+            .iter()
+            .map(|(&vertex, &distance)| (OrderedFloat(-distance), vertex))
+            .collect();
+
+        Self {
+            heap: BinaryHeap::from(heap_vec),
+            distances,
+            stale: 0,
         }
     }
 
+    /// Bulk-build a fast block heap from an iterator of `(vertex, distance)` pairs
+    pub fn from_iter<I: IntoIterator<Item = (usize, T)>>(entries: I) -> Self {
+        Self::from_vec(entries.into_iter().collect())
+    }
+
     /// Add or update a vertex with a distance
     ///
     /// For decrease-key operations, we simply push a new entry and mark the old one as stale.
-    /// Stale entries are filtered out during pop_block.
+    /// Stale entries are filtered out during pop_block, and once they build up past half
+    /// the heap's size a compaction drops them eagerly (see `maybe_compact`).
     pub fn push(&mut self, vertex: usize, distance: T) {
         // Negate distance for min-heap behavior (BinaryHeap is max-heap)
         // We use OrderedFloat with negated value
         let neg_dist = -distance;
+        if self.distances.contains_key(&vertex) {
+            self.stale += 1;
+        }
         self.heap.push((OrderedFloat(neg_dist), vertex));
         self.distances.insert(vertex, distance);
+        self.maybe_compact();
     }
 
     /// Decrease the distance for a vertex (if new distance is smaller)
@@ -300,50 +1224,64 @@ where
     /// Returns the vertices and their distances, ordered by distance.
     /// Also returns the next distance threshold (b_next) if heap is not empty.
     ///
-    /// This method uses lazy deletion: it skips entries where the stored distance
-    /// doesn't match the current distance in the distances map.
+    /// This pops directly from the `BinaryHeap`, discarding stale entries
+    /// (ones whose stored distance no longer matches the `distances` map)
+    /// as it goes, so extracting a block of `m` vertices costs O(m log n)
+    /// rather than the O(n log n) of collecting, filtering, sorting, and
+    /// rebuilding the whole heap.
     pub fn pop_block(&mut self, max_size: usize) -> (Vec<(usize, T)>, Option<T>) {
-        // Collect all entries from heap
-        let all_entries: Vec<_> = std::mem::take(&mut self.heap).into_iter().collect();
-        
-        // Filter out stale entries and collect valid ones
-        let mut valid_entries: Vec<(T, usize)> = Vec::new();
-        for (OrderedFloat(neg_dist), vertex) in all_entries {
-            let stored_dist = -neg_dist;
-            if let Some(&current_dist) = self.distances.get(&vertex) {
-                if stored_dist == current_dist {
-                    valid_entries.push((stored_dist, vertex));
+        let mut block = Vec::with_capacity(max_size.min(self.distances.len()));
+        while block.len() < max_size {
+            let Some((OrderedFloat(neg_dist), vertex)) = self.heap.pop() else {
+                break;
+            };
+            let dist = -neg_dist;
+            match self.distances.get(&vertex) {
+                Some(&current) if current == dist => {
+                    self.distances.remove(&vertex);
+                    block.push((vertex, dist));
+                }
+                _ => {
+                    self.stale = self.stale.saturating_sub(1);
                 }
             }
         }
-        
-        // Sort valid entries by distance
-        valid_entries.sort_by(|a, b| {
-            a.0.partial_cmp(&b.0).unwrap_or(std::cmp::Ordering::Equal)
-        });
-        
-        // Take up to max_size entries for the block
-        let block_size = valid_entries.len().min(max_size);
-        let mut block = Vec::new();
-        for (dist, vertex) in valid_entries.iter().take(block_size) {
-            self.distances.remove(vertex);
-            block.push((*vertex, *dist));
+
+        let b_next = self.peek_min();
+        (block, b_next)
+    }
+
+    /// Peek the smallest live distance without discarding it, eagerly
+    /// popping (and dropping from `stale`) any stale entries found on top
+    /// of the heap along the way
+    fn peek_min(&mut self) -> Option<T> {
+        loop {
+            let &(OrderedFloat(neg_dist), vertex) = self.heap.peek()?;
+            let dist = -neg_dist;
+            match self.distances.get(&vertex) {
+                Some(&current) if current == dist => return Some(dist),
+                _ => {
+                    self.heap.pop();
+                    self.stale = self.stale.saturating_sub(1);
+                }
+            }
         }
-        
-        // Rebuild heap with remaining valid entries
-        for (dist, vertex) in valid_entries.into_iter().skip(block_size) {
-            let neg_dist = -dist;
-            self.heap.push((OrderedFloat(neg_dist), vertex));
+    }
+
+    /// If stale entries have piled up past half the heap's size, rebuild
+    /// the heap keeping only entries that match the current `distances`
+    /// value for their vertex
+    fn maybe_compact(&mut self) {
+        if self.stale > self.heap.len() / 2 {
+            let live: Vec<(OrderedFloat<T>, usize)> = std::mem::take(&mut self.heap)
+                .into_iter()
+                .filter(|&(OrderedFloat(neg_dist), vertex)| {
+                    self.distances.get(&vertex) == Some(&(-neg_dist))
+                })
+                .collect();
+            self.heap = BinaryHeap::from(live);
+            self.stale = 0;
         }
-        
-        // Get next distance threshold
-        let b_next = if let Some(&(OrderedFloat(neg_dist), _)) = self.heap.peek() {
-            Some(-neg_dist)
-        } else {
-            None
-        };
-        
-        (block, b_next)
     }
 
     /// Check if the heap is empty
@@ -379,10 +1317,62 @@ where
     }
 }
 
+impl<T> Frontier<T> for FastBlockHeap<T>
+where
+    T: Float + Copy,
+{
+    fn push(&mut self, vertex: usize, distance: T) {
+        FastBlockHeap::push(self, vertex, distance)
+    }
+    fn decrease_key(&mut self, vertex: usize, new_distance: T) {
+        FastBlockHeap::decrease_key(self, vertex, new_distance)
+    }
+    fn pop_block(&mut self, max_size: usize) -> (Vec<(usize, T)>, Option<T>) {
+        FastBlockHeap::pop_block(self, max_size)
+    }
+    fn is_empty(&self) -> bool {
+        FastBlockHeap::is_empty(self)
+    }
+    fn min_distance(&self) -> Option<T> {
+        FastBlockHeap::min_distance(self)
+    }
+}
+
 #[cfg(test)]
 mod fast_block_heap_tests {
     use super::*;
 
+    #[test]
+    fn test_fast_stale_entries_compact_and_stay_correct() {
+        let mut heap = FastBlockHeap::new();
+        // Repeatedly re-push the same vertex with a smaller distance so
+        // that stale entries pile up and trigger at least one compaction.
+        for i in (0..20).rev() {
+            heap.push(0, i as f32);
+        }
+        heap.push(1, 0.5f32);
+
+        let (block, _) = heap.pop_block(2);
+        assert_eq!(block, vec![(0, 0.0), (1, 0.5)]);
+        assert!(heap.is_empty());
+    }
+
+    #[test]
+    fn test_fast_pop_block_skips_stale_entries_without_full_rebuild() {
+        let mut heap = FastBlockHeap::new();
+        heap.push(0, 5.0f32);
+        heap.push(0, 1.0f32); // supersedes the first push, leaving a stale entry
+        heap.push(1, 2.0f32);
+
+        let (block, b_next) = heap.pop_block(1);
+        assert_eq!(block, vec![(0, 1.0)]);
+        assert_eq!(b_next, Some(2.0));
+
+        let (block, _) = heap.pop_block(1);
+        assert_eq!(block, vec![(1, 2.0)]);
+        assert!(heap.is_empty());
+    }
+
     #[test]
     fn test_fast_push_pop() {
         let mut heap = FastBlockHeap::new();
@@ -408,6 +1398,33 @@ mod fast_block_heap_tests {
         assert_eq!(block[0].1, 2.0);
     }
     
+    #[test]
+    fn test_fast_from_vec_matches_incremental_push() {
+        let pairs = vec![(3usize, 3.0f32), (1, 1.0), (2, 2.0), (0, 0.0)];
+
+        let mut pushed = FastBlockHeap::new();
+        for &(v, d) in &pairs {
+            pushed.push(v, d);
+        }
+        let mut built = FastBlockHeap::from_vec(pairs);
+
+        let mut pushed_out = Vec::new();
+        let mut built_out = Vec::new();
+        while !pushed.is_empty() {
+            pushed_out.push(pushed.pop_block(1).0[0]);
+        }
+        while !built.is_empty() {
+            built_out.push(built.pop_block(1).0[0]);
+        }
+        assert_eq!(pushed_out, built_out);
+    }
+
+    #[test]
+    fn test_fast_from_vec_keeps_smallest_distance_for_duplicate_vertex() {
+        let heap = FastBlockHeap::from_vec(vec![(0usize, 5.0f32), (0, 1.0), (0, 3.0)]);
+        assert_eq!(heap.min_distance(), Some(1.0));
+    }
+
     #[test]
     fn test_fast_is_empty() {
         let mut heap = FastBlockHeap::new();
@@ -574,3 +1591,255 @@ mod fast_block_heap_tests {
 // Pairing heap can be implemented later if benchmarking shows it's beneficial.
 // Pairing heap would require a more complex tree-based structure with O(1) amortized
 // decrease-key operations, but implementation complexity is significant.
+
+/// Bucket (radix) priority queue implementing Dial's algorithm
+///
+/// For graphs whose integer (or pre-quantized) edge weights are bounded by
+/// some constant `C`, this beats the comparison-based heaps above: push and
+/// decrease-key are O(1), and a full drain costs O(m + C) rather than
+/// O(m log n). Distances are stored as `usize` buckets indexed by
+/// `distance mod num_buckets`; `num_buckets` must be sized so that no two
+/// simultaneously-live vertices can collide on the same bucket index,
+/// e.g. `C * max_degree + 1` as in the original Dial's construction.
+///
+/// Like [`FastBlockHeap`], decrease-key uses lazy deletion: the old bucket
+/// entry is left in place and skipped as stale when its distance no longer
+/// matches the authoritative value in `distances`.
+pub struct BucketHeap {
+    /// Circular array of buckets, each holding vertex ids due at that slot
+    buckets: Vec<Vec<usize>>,
+    /// Number of buckets (the circular array's modulus)
+    num_buckets: usize,
+    /// Index of the bucket the monotone cursor currently sits on
+    cursor: usize,
+    /// Real (unwrapped) distance value corresponding to `cursor`
+    current_dist: usize,
+    /// Map from vertex to its current authoritative distance
+    distances: HashMap<usize, usize>,
+}
+
+impl BucketHeap {
+    /// Create a new bucket heap sized for weights bounded by `max_weight`
+    /// over vertices with at most `max_degree` outgoing edges.
+    pub fn new(max_weight: usize, max_degree: usize) -> Self {
+        let num_buckets = max_weight.saturating_mul(max_degree).saturating_add(1).max(1);
+        Self {
+            buckets: vec![Vec::new(); num_buckets],
+            num_buckets,
+            cursor: 0,
+            current_dist: 0,
+            distances: HashMap::new(),
+        }
+    }
+
+    /// Add or update a vertex with an integer distance
+    pub fn push(&mut self, vertex: usize, distance: usize) {
+        self.distances.insert(vertex, distance);
+        let idx = distance % self.num_buckets;
+        self.buckets[idx].push(vertex);
+    }
+
+    /// Decrease the distance for a vertex (if the new distance is smaller)
+    pub fn decrease_key(&mut self, vertex: usize, new_distance: usize) {
+        match self.distances.get(&vertex) {
+            Some(&old) if new_distance < old => self.push(vertex, new_distance),
+            None => self.push(vertex, new_distance),
+            _ => {}
+        }
+    }
+
+    /// Check if the heap is empty
+    pub fn is_empty(&self) -> bool {
+        self.distances.is_empty()
+    }
+
+    /// Get the minimum distance in the heap (if any)
+    pub fn min_distance(&self) -> Option<usize> {
+        self.distances.values().copied().min()
+    }
+
+    /// Pop a block of up to `max_size` vertices with the smallest distances
+    ///
+    /// Advances the monotone cursor over buckets, draining each bucket of
+    /// its live (non-stale) entries before moving on, until `max_size`
+    /// vertices are collected or the heap empties.
+    pub fn pop_block(&mut self, max_size: usize) -> (Vec<(usize, usize)>, Option<usize>) {
+        let mut block = Vec::new();
+
+        while block.len() < max_size && !self.is_empty() {
+            while self.buckets[self.cursor].is_empty() {
+                self.cursor = (self.cursor + 1) % self.num_buckets;
+                self.current_dist += 1;
+            }
+
+            let bucket = std::mem::take(&mut self.buckets[self.cursor]);
+            let mut leftover = Vec::new();
+            for vertex in bucket {
+                if self.distances.get(&vertex) == Some(&self.current_dist) {
+                    if block.len() < max_size {
+                        self.distances.remove(&vertex);
+                        block.push((vertex, self.current_dist));
+                    } else {
+                        leftover.push(vertex);
+                    }
+                }
+                // Otherwise this is a stale entry left behind by a
+                // decrease-key call; simply drop it.
+            }
+            self.buckets[self.cursor] = leftover;
+
+            if self.buckets[self.cursor].is_empty() {
+                self.cursor = (self.cursor + 1) % self.num_buckets;
+                self.current_dist += 1;
+            }
+        }
+
+        let b_next = self.min_distance();
+        (block, b_next)
+    }
+}
+
+/// Selects which [`Frontier`] backend to build at runtime
+///
+/// Lets a caller (or a benchmark sweep) pick the frontier heap by value
+/// instead of hard-coding a type parameter; pair with [`FrontierKind::build`]
+/// to get a boxed trait object ready for [`crate::bmssp::bmssp_sssp_with_frontier`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FrontierKind {
+    /// Cache-sized sorted blocks ([`BlockHeap`])
+    Block,
+    /// Lazy-deletion binary heap with bounded stale-entry compaction ([`FastBlockHeap`])
+    Fast,
+    /// Indexed binary heap with true O(log n) decrease-key ([`IndexedBlockHeap`])
+    Indexed,
+    /// Pairing heap with O(1) amortized decrease-key ([`PairingBlockHeap`])
+    Pairing,
+}
+
+impl FrontierKind {
+    /// Construct a fresh, empty frontier of this kind, boxed as a trait object
+    pub fn build<T>(self) -> Box<dyn Frontier<T>>
+    where
+        T: Float + Copy + 'static,
+    {
+        match self {
+            FrontierKind::Block => Box::new(BlockHeap::new()),
+            FrontierKind::Fast => Box::new(FastBlockHeap::new()),
+            FrontierKind::Indexed => Box::new(IndexedBlockHeap::new()),
+            FrontierKind::Pairing => Box::new(PairingBlockHeap::new()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod frontier_kind_tests {
+    use super::*;
+
+    #[test]
+    fn test_frontier_kind_build_all_variants_behave_consistently() {
+        for kind in [
+            FrontierKind::Block,
+            FrontierKind::Fast,
+            FrontierKind::Indexed,
+            FrontierKind::Pairing,
+        ] {
+            let mut frontier: Box<dyn Frontier<f32>> = kind.build();
+            frontier.push(0, 3.0);
+            frontier.push(1, 1.0);
+            frontier.decrease_key(0, 0.5);
+
+            let (block, _) = frontier.pop_block(2);
+            assert_eq!(block, vec![(0, 0.5), (1, 1.0)]);
+            assert!(frontier.is_empty());
+        }
+    }
+
+    #[test]
+    fn test_into_sorted_vec_ascending_across_backends() {
+        for kind in [
+            FrontierKind::Block,
+            FrontierKind::Fast,
+            FrontierKind::Indexed,
+            FrontierKind::Pairing,
+        ] {
+            let mut frontier: Box<dyn Frontier<f32>> = kind.build();
+            frontier.push(2, 5.0);
+            frontier.push(0, 1.0);
+            frontier.push(1, 3.0);
+
+            let sorted = frontier.into_sorted_vec();
+            assert_eq!(sorted, vec![(0, 1.0), (1, 3.0), (2, 5.0)]);
+        }
+    }
+
+    #[test]
+    fn test_drain_sorted_yields_ascending_order() {
+        let mut heap = FastBlockHeap::new();
+        heap.push(2, 5.0f32);
+        heap.push(0, 1.0);
+        heap.push(1, 3.0);
+
+        let drained: Vec<(usize, f32)> = heap.drain_sorted().collect();
+        assert_eq!(drained, vec![(0, 1.0), (1, 3.0), (2, 5.0)]);
+        assert!(heap.is_empty());
+    }
+}
+
+#[cfg(test)]
+mod bucket_heap_tests {
+    use super::*;
+
+    #[test]
+    fn test_bucket_push_pop() {
+        let mut heap = BucketHeap::new(10, 4);
+        heap.push(0, 3);
+        heap.push(1, 1);
+        heap.push(2, 2);
+
+        let (block, _) = heap.pop_block(3);
+        assert_eq!(block, vec![(1, 1), (2, 2), (0, 3)]);
+        assert!(heap.is_empty());
+    }
+
+    #[test]
+    fn test_bucket_decrease_key() {
+        let mut heap = BucketHeap::new(10, 4);
+        heap.push(0, 5);
+        heap.decrease_key(0, 2);
+        heap.decrease_key(0, 8); // should be ignored (not a decrease)
+
+        let (block, _) = heap.pop_block(1);
+        assert_eq!(block, vec![(0, 2)]);
+        assert!(heap.is_empty());
+    }
+
+    #[test]
+    fn test_bucket_min_distance() {
+        let mut heap = BucketHeap::new(10, 4);
+        assert_eq!(heap.min_distance(), None);
+        heap.push(0, 7);
+        heap.push(1, 3);
+        assert_eq!(heap.min_distance(), Some(3));
+    }
+
+    #[test]
+    fn test_bucket_block_extraction_in_order() {
+        let mut heap = BucketHeap::new(20, 2);
+        for i in 0..10 {
+            heap.push(i, 9 - i);
+        }
+
+        let (block, _) = heap.pop_block(4);
+        assert_eq!(block.len(), 4);
+        for w in block.windows(2) {
+            assert!(w[0].1 <= w[1].1);
+        }
+
+        let mut total = block.len();
+        while !heap.is_empty() {
+            let (rest, _) = heap.pop_block(4);
+            total += rest.len();
+        }
+        assert_eq!(total, 10);
+    }
+}
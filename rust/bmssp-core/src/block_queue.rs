@@ -0,0 +1,127 @@
+use num_traits::Float;
+
+/// Two-level bucket structure backing the recursive BMSSP frontier
+///
+/// Supports the three operations the recursive algorithm needs:
+/// `insert` (append a single vertex/key pair, as ordinary relaxation
+/// produces them one at a time), `batch_prepend` (splice in a whole batch
+/// of vertices known to carry small keys, e.g. the result of a recursive
+/// call, ahead of anything already queued), and `pull` (take the smallest
+/// `m` keys currently queued plus a separating bound for what's left).
+///
+/// Internally this is just a deque of batches: `insert` appends to the
+/// newest back-batch (splitting once it reaches `batch_size`),
+/// `batch_prepend` pushes a whole new batch to the front, and `pull`
+/// flattens + sorts once per call. That sort makes `pull` O(size log size)
+/// rather than the amortized-constant bound the originating paper's
+/// structure achieves.
+pub struct BlockQueue<T> {
+    batches: std::collections::VecDeque<Vec<(usize, T)>>,
+    batch_size: usize,
+}
+
+impl<T> BlockQueue<T>
+where
+    T: Float + Copy,
+{
+    /// Create an empty queue, batching plain `insert`s in groups of
+    /// `batch_size` (clamped to at least 1)
+    pub fn new(batch_size: usize) -> Self {
+        Self {
+            batches: std::collections::VecDeque::new(),
+            batch_size: batch_size.max(1),
+        }
+    }
+
+    /// Insert a single vertex/key pair
+    pub fn insert(&mut self, vertex: usize, key: T) {
+        match self.batches.back_mut() {
+            Some(batch) if batch.len() < self.batch_size => batch.push((vertex, key)),
+            _ => self.batches.push_back(vec![(vertex, key)]),
+        }
+    }
+
+    /// Splice in a whole batch of vertex/key pairs ahead of everything
+    /// already queued (e.g. the completed set from a recursive call, whose
+    /// keys are all known to be small relative to the current bound)
+    pub fn batch_prepend(&mut self, items: Vec<(usize, T)>) {
+        if !items.is_empty() {
+            self.batches.push_front(items);
+        }
+    }
+
+    /// Pull the `m` smallest-key entries across the whole queue, plus any
+    /// further entries that tie the last pulled key (so more than `m` can
+    /// come back)
+    ///
+    /// Returns the pulled `(vertex, key)` pairs (sorted ascending by key)
+    /// and, if anything remains, the smallest key left in the queue — the
+    /// separating bound between what was pulled and what wasn't.
+    pub fn pull(&mut self, m: usize) -> (Vec<(usize, T)>, Option<T>) {
+        let mut all: Vec<(usize, T)> = self.batches.drain(..).flatten().collect();
+        all.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal));
+
+        let mut take = all.len().min(m);
+        // Never split a tie across the cutoff: if the next entry shares the
+        // same key as the last one taken, absorb it too, so the returned
+        // bound is always strictly greater than every key just pulled
+        // (otherwise a caller handed that bound as an exclusive upper limit
+        // for its next recursive step would see a zero-width range and make
+        // no progress).
+        while take < all.len() && take > 0 && all[take].1 == all[take - 1].1 {
+            take += 1;
+        }
+        let rest = all.split_off(take);
+        let bound = rest.first().map(|&(_, key)| key);
+        if !rest.is_empty() {
+            self.batches.push_back(rest);
+        }
+
+        (all, bound)
+    }
+
+    /// True if no vertices remain queued
+    pub fn is_empty(&self) -> bool {
+        self.batches.iter().all(|batch| batch.is_empty())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_block_queue_pull_returns_smallest_keys_and_bound() {
+        let mut q: BlockQueue<f32> = BlockQueue::new(4);
+        q.insert(0, 5.0);
+        q.insert(1, 1.0);
+        q.insert(2, 3.0);
+        q.insert(3, 2.0);
+
+        let (block, bound) = q.pull(2);
+        assert_eq!(block, vec![(1, 1.0), (3, 2.0)]);
+        assert_eq!(bound, Some(3.0));
+        assert!(!q.is_empty());
+    }
+
+    #[test]
+    fn test_block_queue_batch_prepend_participates_in_next_pull() {
+        let mut q: BlockQueue<f32> = BlockQueue::new(4);
+        q.insert(0, 10.0);
+        q.batch_prepend(vec![(1, 0.5)]);
+
+        let (block, bound) = q.pull(1);
+        assert_eq!(block, vec![(1, 0.5)]);
+        assert_eq!(bound, Some(10.0));
+    }
+
+    #[test]
+    fn test_block_queue_empty_after_draining() {
+        let mut q: BlockQueue<f32> = BlockQueue::new(2);
+        q.insert(0, 1.0);
+        assert!(!q.is_empty());
+        let (_block, bound) = q.pull(10);
+        assert_eq!(bound, None);
+        assert!(q.is_empty());
+    }
+}
@@ -1,7 +1,10 @@
-use crate::block_heap::FastBlockHeap;
+use crate::bitset::EnabledMask;
+use crate::block_heap::{BlockHeap, FastBlockHeap, Frontier};
 use crate::csr::CsrGraph;
+use crate::dijkstra::build_shortest_path_dag;
 use crate::error::Result;
 use crate::params::BmsspParams;
+use crate::validation;
 use num_traits::Float;
 
 #[cfg(feature = "simd")]
@@ -218,13 +221,182 @@ pub fn bmssp_sssp_with_preds<T>(
 where
     T: Float + Copy + Send + Sync + 'static,
 {
+    bmssp_sssp_with_preds_multi(graph, weights, &[source], None, enabled)
+}
+
+/// BMSSP single-source search generic over the frontier heap implementation
+///
+/// Runs the same block-relaxation loop as [`bmssp_sssp_with_preds`], but
+/// the caller supplies the frontier as `&mut dyn Frontier<T>` instead of a
+/// hard-coded [`FastBlockHeap`], so a different backend --
+/// [`crate::block_heap::IndexedBlockHeap`], [`crate::block_heap::PairingBlockHeap`],
+/// or [`BlockHeap`] -- can be selected per call, at runtime, e.g. via
+/// [`crate::block_heap::FrontierKind`]. This single-threaded loop mirrors
+/// the `not(feature = "parallel")` branch of [`bmssp_sssp_with_preds_multi`];
+/// it intentionally doesn't generalize the rayon/SIMD fast paths, which are
+/// specialized to `FastBlockHeap`'s internals.
+pub fn bmssp_sssp_with_frontier<T>(
+    graph: &CsrGraph,
+    weights: &[T],
+    source: usize,
+    enabled: Option<&[bool]>,
+    frontier: &mut dyn Frontier<T>,
+) -> Result<(Vec<T>, Vec<usize>)>
+where
+    T: Float + Copy,
+{
+    validation::validate_source(graph, source)?;
+
     let n = graph.num_vertices();
     let mut dist = vec![T::infinity(); n];
     let mut pred = vec![usize::MAX; n];
-    
     dist[source] = T::zero();
     pred[source] = source;
-    
+
+    if n <= 4 {
+        let mut changed = true;
+        for _ in 0..n {
+            if !changed {
+                break;
+            }
+            changed = false;
+            for u in 0..n {
+                if !dist[u].is_finite() {
+                    continue;
+                }
+                let (start, _end) = graph.edge_range(u);
+                for (eid, &v) in graph.neighbors(u).iter().enumerate() {
+                    let edge_idx = start + eid;
+                    if let Some(mask) = enabled {
+                        if !mask[edge_idx] {
+                            continue;
+                        }
+                    }
+                    let w = weights[edge_idx];
+                    let new_dist = dist[u] + w;
+                    if new_dist < dist[v] {
+                        dist[v] = new_dist;
+                        pred[v] = u;
+                        changed = true;
+                    }
+                }
+            }
+        }
+        return Ok((dist, pred));
+    }
+
+    let params = BmsspParams::from_n(n);
+    frontier.push(source, dist[source]);
+
+    while !frontier.is_empty() {
+        let (block, _b_next) = frontier.pop_block(params.k);
+        for (u, d) in block {
+            if d > dist[u] {
+                continue;
+            }
+            let (start, _end) = graph.edge_range(u);
+            for (eid, &v) in graph.neighbors(u).iter().enumerate() {
+                let edge_idx = start + eid;
+                if let Some(mask) = enabled {
+                    if !mask[edge_idx] {
+                        continue;
+                    }
+                }
+                let w = weights[edge_idx];
+                let new_dist = d + w;
+                if new_dist < dist[v] {
+                    dist[v] = new_dist;
+                    pred[v] = u;
+                    frontier.decrease_key(v, new_dist);
+                }
+            }
+        }
+    }
+
+    Ok((dist, pred))
+}
+
+/// BMSSP that returns the full shortest-path DAG instead of a single
+/// predecessor per vertex
+///
+/// `preds[v]` lists every incoming edge lying on some shortest path to `v`
+/// (within `epsilon`, to tolerate floating-point tie noise), and
+/// `sigma[v]` is the number of distinct shortest paths to `v`. See
+/// [`crate::dijkstra::dijkstra_sssp_dag`] for the equivalent unbounded
+/// variant; both share the same DAG-construction pass over the final
+/// distance array.
+pub fn bmssp_sssp_dag<T>(
+    graph: &CsrGraph,
+    weights: &[T],
+    source: usize,
+    enabled: Option<&[bool]>,
+    epsilon: f64,
+) -> Result<(Vec<T>, Vec<Vec<usize>>, Vec<u64>)>
+where
+    T: Float + Copy + Send + Sync + 'static,
+{
+    let dist = bmssp_sssp(graph, weights, source, enabled)?;
+    let (preds, sigma) = build_shortest_path_dag(graph, weights, enabled, &dist, source, epsilon);
+    Ok((dist, preds, sigma))
+}
+
+/// BMSSP algorithm for bounded multi-source shortest paths
+///
+/// Runs the same block-based expansion as [`bmssp_sssp`], but seeded from
+/// several sources at once: `dist[v]` ends up holding the minimum distance
+/// from *any* seed to `v`. This is the "super-source" pattern and is
+/// equivalent to adding a virtual source with zero-weight edges to each
+/// seed, without materializing those edges.
+pub fn bmssp_sssp_multi<T>(
+    graph: &CsrGraph,
+    weights: &[T],
+    sources: &[usize],
+    initial_dist: Option<&[T]>,
+    enabled: Option<&[bool]>,
+) -> Result<Vec<T>>
+where
+    T: Float + Copy + Send + Sync + 'static,
+{
+    let (dist, _) = bmssp_sssp_with_preds_multi(graph, weights, sources, initial_dist, enabled)?;
+    Ok(dist)
+}
+
+/// BMSSP algorithm with predecessor tracking, seeded from multiple sources
+///
+/// `sources` gives the seed vertices; `initial_dist` optionally gives each
+/// seed's starting distance (defaulting to zero for all seeds when `None`,
+/// of the same length as `sources`). The resulting `pred[v]` points back
+/// toward whichever seed `v` was reached from.
+///
+/// This is the general form of the algorithm: [`bmssp_sssp_with_preds`]
+/// is simply this function called with a single seed at distance zero.
+pub fn bmssp_sssp_with_preds_multi<T>(
+    graph: &CsrGraph,
+    weights: &[T],
+    sources: &[usize],
+    initial_dist: Option<&[T]>,
+    enabled: Option<&[bool]>,
+) -> Result<(Vec<T>, Vec<usize>)>
+where
+    T: Float + Copy + Send + Sync + 'static,
+{
+    validation::validate_sources(graph, sources)?;
+    if let Some(init) = initial_dist {
+        validation::validate_source_dist_len(sources.len(), init.len())?;
+    }
+
+    let n = graph.num_vertices();
+    let mut dist = vec![T::infinity(); n];
+    let mut pred = vec![usize::MAX; n];
+
+    for (i, &source) in sources.iter().enumerate() {
+        let d0 = initial_dist.map_or(T::zero(), |init| init[i]);
+        if d0 < dist[source] {
+            dist[source] = d0;
+            pred[source] = source;
+        }
+    }
+
     // For very small graphs, use simple edge relaxation
     if n <= 4 {
         let mut changed = true;
@@ -237,10 +409,10 @@ where
                 if !dist[u].is_finite() {
                     continue;
                 }
-                let (start, end) = graph.edge_range(u);
+                let (start, _end) = graph.edge_range(u);
                 for (eid, &v) in graph.neighbors(u).iter().enumerate() {
                     let edge_idx = start + eid;
-                    
+
                     if let Some(enabled_mask) = enabled {
                         if !enabled_mask[edge_idx] {
                             continue;
@@ -264,15 +436,17 @@ where
     // Compute parameters for block processing
     let params = BmsspParams::from_n(n);
     
-    // Initialize block heap with source
+    // Initialize block heap with every seed
     let mut heap = FastBlockHeap::new();
-    heap.push(source, T::zero());
-    
+    for &source in sources {
+        heap.push(source, dist[source]);
+    }
+
     // Main loop: process blocks
     while !heap.is_empty() {
         // Extract a block of up to k vertices
         let (block, _b_next) = heap.pop_block(params.k);
-        
+
         #[cfg(feature = "parallel")]
         {
             let dist_snapshot = &dist[..];
@@ -334,6 +508,439 @@ where
     Ok((dist, pred))
 }
 
+/// BMSSP with predecessor tracking, using epsilon-approximate quantile
+/// thresholds to extract frontier blocks instead of exact block counts.
+///
+/// This is the opt-in counterpart to [`bmssp_sssp_with_preds`]: instead of
+/// popping an exact `k`-sized block from a [`FastBlockHeap`], it pops every
+/// vertex at or below the current frontier's `k/len` quantile from a
+/// [`BlockHeap`], estimated by a [`crate::quantile::GkSummary`] that each
+/// pop rebuilds from every frontier entry, so a pop costs time linear in
+/// the frontier size; no sort is done. Useful when the frontier is large and
+/// a small bounded rank error (`epsilon`) is an acceptable trade for
+/// avoiding repeated full comparisons.
+pub fn bmssp_sssp_with_preds_tolerant<T>(
+    graph: &CsrGraph,
+    weights: &[T],
+    source: usize,
+    enabled: Option<&[bool]>,
+    epsilon: f64,
+) -> Result<(Vec<T>, Vec<usize>)>
+where
+    T: Float + Copy,
+{
+    let n = graph.num_vertices();
+    let mut dist = vec![T::infinity(); n];
+    let mut pred = vec![usize::MAX; n];
+
+    dist[source] = T::zero();
+    pred[source] = source;
+
+    // For very small graphs, use simple edge relaxation (same fallback as
+    // the exact solver; the approximate frontier only pays off once there
+    // are enough vertices in flight to make sorting costly).
+    if n <= 4 {
+        let mut changed = true;
+        for _ in 0..n {
+            if !changed {
+                break;
+            }
+            changed = false;
+            for u in 0..n {
+                if !dist[u].is_finite() {
+                    continue;
+                }
+                let (start, _end) = graph.edge_range(u);
+                for (eid, &v) in graph.neighbors(u).iter().enumerate() {
+                    let edge_idx = start + eid;
+
+                    if let Some(enabled_mask) = enabled {
+                        if !enabled_mask[edge_idx] {
+                            continue;
+                        }
+                    }
+
+                    let w = weights[edge_idx];
+                    let new_dist = dist[u] + w;
+
+                    if new_dist < dist[v] {
+                        dist[v] = new_dist;
+                        pred[v] = u;
+                        changed = true;
+                    }
+                }
+            }
+        }
+        return Ok((dist, pred));
+    }
+
+    let params = BmsspParams::from_n(n).with_epsilon(epsilon);
+
+    let mut heap = BlockHeap::new();
+    heap.push(source, T::zero());
+
+    while !heap.is_empty() {
+        let phi = (params.k as f64 / heap.len() as f64).min(1.0);
+        let (block, _b_next) = heap.pop_approx_block(phi, epsilon);
+
+        for (u, d) in block {
+            if d > dist[u] {
+                continue;
+            }
+
+            let (start, _end) = graph.edge_range(u);
+            for (eid, &v) in graph.neighbors(u).iter().enumerate() {
+                let edge_idx = start + eid;
+
+                if let Some(enabled_mask) = enabled {
+                    if !enabled_mask[edge_idx] {
+                        continue;
+                    }
+                }
+
+                let w = weights[edge_idx];
+                let new_dist = dist[u] + w;
+
+                if new_dist < dist[v] {
+                    dist[v] = new_dist;
+                    pred[v] = u;
+                    heap.push(v, new_dist);
+                }
+            }
+        }
+    }
+
+    Ok((dist, pred))
+}
+
+/// BMSSP algorithm for "nearest facility" / weighted Voronoi queries
+///
+/// `sources` pairs each seed vertex with its own starting offset (pass
+/// `T::zero()` for a plain multi-source frontier, or a per-seed offset for a
+/// weighted "closest facility" query). Besides the usual distance and
+/// predecessor arrays, this also returns `source_of: Vec<usize>`, where
+/// `source_of[v]` is the seed vertex that `v` was ultimately reached from
+/// (i.e. which facility owns `v` in the induced Voronoi partition).
+///
+/// This reuses the same relax/block machinery as
+/// [`bmssp_sssp_with_preds_multi`]; the only addition is propagating
+/// ownership alongside `pred` on every improving relaxation.
+pub fn bmssp_multi_source<T>(
+    graph: &CsrGraph,
+    weights: &[T],
+    sources: &[(usize, T)],
+    enabled: Option<&[bool]>,
+) -> Result<(Vec<T>, Vec<usize>, Vec<usize>)>
+where
+    T: Float + Copy + Send + Sync + 'static,
+{
+    let n = graph.num_vertices();
+    let mut dist = vec![T::infinity(); n];
+    let mut pred = vec![usize::MAX; n];
+    let mut owner = vec![usize::MAX; n];
+
+    for &(source, offset) in sources {
+        if offset < dist[source] {
+            dist[source] = offset;
+            pred[source] = source;
+            owner[source] = source;
+        }
+    }
+
+    // For very small graphs, use simple edge relaxation (same fallback as
+    // the other BMSSP entry points).
+    if n <= 4 {
+        let mut changed = true;
+        for _ in 0..n {
+            if !changed {
+                break;
+            }
+            changed = false;
+            for u in 0..n {
+                if !dist[u].is_finite() {
+                    continue;
+                }
+                let (start, _end) = graph.edge_range(u);
+                for (eid, &v) in graph.neighbors(u).iter().enumerate() {
+                    let edge_idx = start + eid;
+
+                    if let Some(enabled_mask) = enabled {
+                        if !enabled_mask[edge_idx] {
+                            continue;
+                        }
+                    }
+
+                    let w = weights[edge_idx];
+                    let new_dist = dist[u] + w;
+
+                    if new_dist < dist[v] {
+                        dist[v] = new_dist;
+                        pred[v] = u;
+                        owner[v] = owner[u];
+                        changed = true;
+                    }
+                }
+            }
+        }
+        return Ok((dist, pred, owner));
+    }
+
+    let params = BmsspParams::from_n(n);
+
+    let mut heap = FastBlockHeap::new();
+    for &(source, _) in sources {
+        heap.push(source, dist[source]);
+    }
+
+    while !heap.is_empty() {
+        let (block, _b_next) = heap.pop_block(params.k);
+
+        for (u, d) in block {
+            if d > dist[u] {
+                continue;
+            }
+
+            let (start, _end) = graph.edge_range(u);
+            for (eid, &v) in graph.neighbors(u).iter().enumerate() {
+                let edge_idx = start + eid;
+
+                if let Some(enabled_mask) = enabled {
+                    if !enabled_mask[edge_idx] {
+                        continue;
+                    }
+                }
+
+                let w = weights[edge_idx];
+                let new_dist = dist[u] + w;
+
+                if new_dist < dist[v] {
+                    dist[v] = new_dist;
+                    pred[v] = u;
+                    owner[v] = owner[u];
+                    heap.push(v, new_dist);
+                }
+            }
+        }
+    }
+
+    Ok((dist, pred, owner))
+}
+
+/// Bounded multi-source relaxation: seed many sources at once (each with
+/// its own optional starting offset) and only settle vertices whose
+/// tentative distance stays strictly below `bound`
+///
+/// Exposes the bounded, multi-source primitive the algorithm's name
+/// already implies as a first-class, simple-output entry point: callers
+/// doing incremental region-growing or all-pairs-style batched queries can
+/// seed many sources in one call instead of reaching for the richer (and
+/// costlier) [`bmssp_multi_source`] or [`crate::recursive::bmssp_multi_source_bounded`].
+/// Vertices never brought under `bound` keep `T::infinity()`, exactly like
+/// [`crate::recursive::bmssp_multi_source_bounded`]'s `distances` field.
+///
+/// Named `bmssp_multi_source_within_bound` rather than `bmssp_multi_source`
+/// (already [`bmssp_multi_source`] above, which has no bound and also
+/// returns per-vertex facility ownership) or `bmssp_multi_source_bounded`
+/// (already [`crate::recursive::bmssp_multi_source_bounded`], which takes
+/// plain unweighted sources, a caller-supplied [`BmsspParams`], and returns
+/// the richer [`crate::recursive::BmsspMultiSourceResult`] including the
+/// top-level pivot set). This entry point instead mirrors
+/// [`bmssp_multi_source`]'s `&[(usize, T)]` per-source-offset sources and
+/// derives its own [`BmsspParams`] from `n`, for callers that just want
+/// `(distances, predecessors)`.
+pub fn bmssp_multi_source_within_bound<T>(
+    graph: &CsrGraph,
+    weights: &[T],
+    sources: &[(usize, T)],
+    bound: T,
+    enabled: Option<&[bool]>,
+) -> Result<(Vec<T>, Vec<usize>)>
+where
+    T: Float + Copy,
+{
+    let n = graph.num_vertices();
+    let mut dist = vec![T::infinity(); n];
+    let mut pred = vec![usize::MAX; n];
+
+    for &(source, offset) in sources {
+        if offset < bound && offset < dist[source] {
+            dist[source] = offset;
+            pred[source] = source;
+        }
+    }
+
+    // For very small graphs, use simple edge relaxation (same fallback as
+    // the other BMSSP entry points).
+    if n <= 4 {
+        let mut changed = true;
+        for _ in 0..n {
+            if !changed {
+                break;
+            }
+            changed = false;
+            for u in 0..n {
+                if !dist[u].is_finite() {
+                    continue;
+                }
+                let (start, _end) = graph.edge_range(u);
+                for (eid, &v) in graph.neighbors(u).iter().enumerate() {
+                    let edge_idx = start + eid;
+
+                    if let Some(enabled_mask) = enabled {
+                        if !enabled_mask[edge_idx] {
+                            continue;
+                        }
+                    }
+
+                    let w = weights[edge_idx];
+                    let new_dist = dist[u] + w;
+
+                    if new_dist < bound && new_dist < dist[v] {
+                        dist[v] = new_dist;
+                        pred[v] = u;
+                        changed = true;
+                    }
+                }
+            }
+        }
+        return Ok((dist, pred));
+    }
+
+    let params = BmsspParams::from_n(n);
+
+    let mut heap = FastBlockHeap::new();
+    for &(source, _) in sources {
+        if dist[source].is_finite() {
+            heap.push(source, dist[source]);
+        }
+    }
+
+    while !heap.is_empty() {
+        let (block, _b_next) = heap.pop_block(params.k);
+
+        for (u, d) in block {
+            if d > dist[u] {
+                continue;
+            }
+
+            let (start, _end) = graph.edge_range(u);
+            for (eid, &v) in graph.neighbors(u).iter().enumerate() {
+                let edge_idx = start + eid;
+
+                if let Some(enabled_mask) = enabled {
+                    if !enabled_mask[edge_idx] {
+                        continue;
+                    }
+                }
+
+                let w = weights[edge_idx];
+                let new_dist = dist[u] + w;
+
+                if new_dist < bound && new_dist < dist[v] {
+                    dist[v] = new_dist;
+                    pred[v] = u;
+                    heap.push(v, new_dist);
+                }
+            }
+        }
+    }
+
+    Ok((dist, pred))
+}
+
+/// Goal-directed single-pair search (A*) over the BMSSP block frontier
+///
+/// Runs the same block-based expansion as [`bmssp_sssp`], but orders the
+/// frontier by `f(v) = dist[v] + heuristic(v)` instead of the raw distance,
+/// so the search is steered toward `target` and typically settles it long
+/// before exploring the whole graph. `dist`/`pred` remain the authoritative
+/// g-score and predecessor arrays throughout — only the heap's priority key
+/// is biased by `heuristic`, exactly as the request's `f(v) = dist[v] +
+/// h(v)` keying scheme. Because blocks can still contain several vertices,
+/// `target` is only accepted as finalized once it is popped off the
+/// frontier with no cheaper entry left on it (an earlier vertex of the same
+/// block may have pushed one, in which case `target` is requeued), at which
+/// point (for an admissible, consistent heuristic) `dist[target]` is
+/// optimal and the loop returns immediately.
+///
+/// Passing a heuristic that always returns `T::zero()` degrades this to
+/// plain Dijkstra, matching [`bmssp_sssp_with_preds`] on the same inputs.
+///
+/// Returns `Ok(None)` if `target` is unreachable from `source`. Otherwise
+/// returns `(distance, path)` where `path` lists vertices from `source` to
+/// `target` inclusive.
+pub fn bmssp_astar<T, H>(
+    graph: &CsrGraph,
+    weights: &[T],
+    source: usize,
+    target: usize,
+    heuristic: H,
+    enabled: Option<&[bool]>,
+) -> Result<Option<(T, Vec<usize>)>>
+where
+    T: Float + Copy,
+    H: Fn(usize) -> T,
+{
+    let n = graph.num_vertices();
+    if source == target {
+        return Ok(Some((T::zero(), vec![source])));
+    }
+
+    let mut dist = vec![T::infinity(); n];
+    let mut pred = vec![usize::MAX; n];
+    dist[source] = T::zero();
+
+    let mut heap = FastBlockHeap::new();
+    heap.push(source, heuristic(source));
+
+    let params = BmsspParams::from_n(n);
+
+    while !heap.is_empty() {
+        let (block, _b_next) = heap.pop_block(params.k);
+
+        for (u, priority) in block {
+            if u == target {
+                // An earlier vertex of this block may have pushed an entry
+                // cheaper than target's; requeue target behind it.
+                if heap.min_distance().is_some_and(|m| m < priority) {
+                    heap.decrease_key(target, priority);
+                    continue;
+                }
+                let mut path = vec![target];
+                let mut cur = target;
+                while cur != source {
+                    cur = pred[cur];
+                    path.push(cur);
+                }
+                path.reverse();
+                return Ok(Some((dist[target], path)));
+            }
+
+            let (start, _end) = graph.edge_range(u);
+            for (eid, &v) in graph.neighbors(u).iter().enumerate() {
+                let edge_idx = start + eid;
+
+                if let Some(enabled_mask) = enabled {
+                    if !enabled_mask[edge_idx] {
+                        continue;
+                    }
+                }
+
+                let w = weights[edge_idx];
+                let new_dist = dist[u] + w;
+
+                if new_dist < dist[v] {
+                    dist[v] = new_dist;
+                    pred[v] = u;
+                    heap.push(v, new_dist + heuristic(v));
+                }
+            }
+        }
+    }
+
+    Ok(None)
+}
+
 /// Reusable state for BMSSP algorithm
 ///
 /// This structure holds buffers that can be reused across multiple SSSP calls,
@@ -345,6 +952,10 @@ pub struct BmsspState<T> {
     predecessors: Vec<usize>,
     /// Block heap (reusable)
     heap: FastBlockHeap<T>,
+    /// Bit-packed enabled mask, reused across queries by
+    /// [`bmssp_sssp_with_preds_and_state_masked`] so a sweep of
+    /// single-edge toggles doesn't pay for a full mask rebuild
+    mask: Option<EnabledMask>,
 }
 
 impl<T> BmsspState<T>
@@ -357,9 +968,35 @@ where
             distances: vec![T::infinity(); n],
             predecessors: vec![usize::MAX; n],
             heap: FastBlockHeap::new(),
+            mask: None,
         }
     }
 
+    /// Install a bit-packed enabled mask to be reused by
+    /// [`bmssp_sssp_with_preds_and_state_masked`] queries against this
+    /// state, replacing whatever mask (if any) was installed before
+    pub fn set_enabled_mask(&mut self, mask: EnabledMask) {
+        self.mask = Some(mask);
+    }
+
+    /// The bit-packed enabled mask currently installed, if any
+    pub fn enabled_mask(&self) -> Option<&EnabledMask> {
+        self.mask.as_ref()
+    }
+
+    /// Flip a single edge's bit in the installed mask without touching the
+    /// rest of the word array
+    ///
+    /// Panics if no mask has been installed via [`Self::set_enabled_mask`]
+    /// yet — call that first (e.g. with `EnabledMask::new(num_edges, true)`
+    /// to start from "everything enabled").
+    pub fn set_edge_enabled(&mut self, edge_idx: usize, value: bool) {
+        self.mask
+            .as_mut()
+            .expect("set_enabled_mask must be called before set_edge_enabled")
+            .set(edge_idx, value);
+    }
+
     /// Reset the state for a new SSSP computation
     ///
     /// This clears the heap and resets distances/predecessors arrays.
@@ -487,32 +1124,146 @@ where
                         return acc;
                     }
 
-                    let (start, _end) = graph.edge_range(*u);
-                    for (eid, &v) in graph.neighbors(*u).iter().enumerate() {
-                        let edge_idx = start + eid;
+                    let (start, _end) = graph.edge_range(*u);
+                    for (eid, &v) in graph.neighbors(*u).iter().enumerate() {
+                        let edge_idx = start + eid;
+
+                        if let Some(enabled_mask) = enabled {
+                            if !enabled_mask[edge_idx] {
+                                continue;
+                            }
+                        }
+
+                        let w = weights[edge_idx];
+                        let new_dist = dist_snapshot[*u] + w;
+
+                        if new_dist < dist_snapshot[v] {
+                            acc.push((v, new_dist, *u));
+                        }
+                    }
+
+                    acc
+                })
+                .reduce(Vec::new, |mut a: Vec<(usize, T, usize)>, mut b| {
+                    a.append(&mut b);
+                    a
+                });
+
+            for (v, new_dist, u) in candidates {
+                if new_dist < dist[v] {
+                    dist[v] = new_dist;
+                    pred[v] = u;
+                    state.heap.push(v, new_dist);
+                }
+            }
+        }
+
+        #[cfg(not(feature = "parallel"))]
+        {
+            // Process each vertex in the block
+            for (u, d) in block {
+                // Skip if we've found a better path
+                if d > dist[u] {
+                    continue;
+                }
+
+                relax_edges(graph, weights, enabled, u, dist, pred, &mut state.heap);
+            }
+        }
+    }
+
+    Ok((dist, pred))
+}
+
+/// [`bmssp_sssp_with_preds_and_state`], but reading edge liveness from the
+/// bit-packed [`EnabledMask`] already installed on `state` via
+/// [`BmsspState::set_enabled_mask`] instead of a fresh `&[bool]` argument
+///
+/// Built for the iterative-analysis pattern of toggling a handful of edges
+/// between otherwise-identical queries (failure simulation, routing-policy
+/// sweeps): [`BmsspState::set_edge_enabled`] flips one bit in place, and
+/// this function re-solves against the updated mask without reallocating
+/// or re-validating the whole word array on every round.
+pub fn bmssp_sssp_with_preds_and_state_masked<'a, T>(
+    state: &'a mut BmsspState<T>,
+    graph: &CsrGraph,
+    weights: &[T],
+    source: usize,
+) -> Result<(&'a [T], &'a [usize])>
+where
+    T: Float + Copy + Send + Sync + 'static,
+{
+    validation::validate_source(graph, source)?;
+    if let Some(mask) = &state.mask {
+        validation::validate_enabled_mask_bits(graph.num_edges(), mask)?;
+    }
+
+    let n = graph.num_vertices();
+    state.reset(n);
+
+    let mask = state.mask.as_ref();
+    let dist = &mut state.distances[..n];
+    let pred = &mut state.predecessors[..n];
+
+    dist[source] = T::zero();
+    pred[source] = source;
+
+    let is_enabled = |edge_idx: usize| mask.is_none_or(|m| m.get(edge_idx));
+
+    // For very small graphs, use simple edge relaxation
+    if n <= 4 {
+        let mut changed = true;
+        for _ in 0..n {
+            if !changed {
+                break;
+            }
+            changed = false;
+            for u in 0..n {
+                if !dist[u].is_finite() {
+                    continue;
+                }
+                let (start, _end) = graph.edge_range(u);
+                for (eid, &v) in graph.neighbors(u).iter().enumerate() {
+                    let edge_idx = start + eid;
+                    if !is_enabled(edge_idx) {
+                        continue;
+                    }
+
+                    let w = weights[edge_idx];
+                    let new_dist = dist[u] + w;
+
+                    if new_dist < dist[v] {
+                        dist[v] = new_dist;
+                        pred[v] = u;
+                        changed = true;
+                    }
+                }
+            }
+        }
+        return Ok((dist, pred));
+    }
+
+    let params = BmsspParams::from_n(n);
+    state.heap.push(source, T::zero());
 
-                        if let Some(enabled_mask) = enabled {
-                            if !enabled_mask[edge_idx] {
-                                continue;
-                            }
-                        }
+    while !state.heap.is_empty() {
+        let (block, _b_next) = state.heap.pop_block(params.k);
 
-                        let w = weights[edge_idx];
-                        let new_dist = dist_snapshot[*u] + w;
+        for (u, d) in block {
+            if d > dist[u] {
+                continue;
+            }
 
-                        if new_dist < dist_snapshot[v] {
-                            acc.push((v, new_dist, *u));
-                        }
-                    }
+            let (start, _end) = graph.edge_range(u);
+            for (eid, &v) in graph.neighbors(u).iter().enumerate() {
+                let edge_idx = start + eid;
+                if !is_enabled(edge_idx) {
+                    continue;
+                }
 
-                    acc
-                })
-                .reduce(Vec::new, |mut a: Vec<(usize, T, usize)>, mut b| {
-                    a.append(&mut b);
-                    a
-                });
+                let w = weights[edge_idx];
+                let new_dist = dist[u] + w;
 
-            for (v, new_dist, u) in candidates {
                 if new_dist < dist[v] {
                     dist[v] = new_dist;
                     pred[v] = u;
@@ -520,29 +1271,149 @@ where
                 }
             }
         }
+    }
 
-        #[cfg(not(feature = "parallel"))]
-        {
-            // Process each vertex in the block
-            for (u, d) in block {
-                // Skip if we've found a better path
-                if d > dist[u] {
+    Ok((dist, pred))
+}
+
+/// Point-to-point shortest-path query with early termination
+///
+/// Identical in spirit to [`bmssp_astar`] with a heuristic of `T::zero()`
+/// (which degrades A* to plain Dijkstra): once `target` is popped off the
+/// frontier with no cheaper entry left on it, its label is final and the
+/// search can stop without relaxing the rest of the graph. Prefer this over [`bmssp_sssp`] for single-pair
+/// queries on large graphs where only one distance is actually needed.
+///
+/// Returns `Ok(None)` if `target` is unreachable from `source`. Otherwise
+/// returns `(distance, path)` where `path` lists vertices from `source` to
+/// `target` inclusive.
+pub fn bmssp_sssp_to_target<T>(
+    graph: &CsrGraph,
+    weights: &[T],
+    source: usize,
+    target: usize,
+    enabled: Option<&[bool]>,
+) -> Result<Option<(T, Vec<usize>)>>
+where
+    T: Float + Copy,
+{
+    bmssp_astar(graph, weights, source, target, |_| T::zero(), enabled)
+}
+
+/// [`bmssp_sssp_to_target`] using a reusable [`BmsspState`] so repeated
+/// point-to-point queries on the same graph don't pay for a fresh
+/// distance/predecessor buffer and heap on every call
+pub fn bmssp_sssp_to_target_with_state<T>(
+    state: &mut BmsspState<T>,
+    graph: &CsrGraph,
+    weights: &[T],
+    source: usize,
+    target: usize,
+    enabled: Option<&[bool]>,
+) -> Result<Option<(T, Vec<usize>)>>
+where
+    T: Float + Copy,
+{
+    let n = graph.num_vertices();
+    if source == target {
+        return Ok(Some((T::zero(), vec![source])));
+    }
+
+    state.reset(n);
+    let dist = &mut state.distances[..n];
+    let pred = &mut state.predecessors[..n];
+    dist[source] = T::zero();
+    pred[source] = source;
+
+    state.heap.push(source, T::zero());
+
+    let params = BmsspParams::from_n(n);
+
+    while !state.heap.is_empty() {
+        let (block, _b_next) = state.heap.pop_block(params.k);
+
+        for (u, d) in block {
+            if d > dist[u] {
+                continue;
+            }
+            if u == target {
+                // As in bmssp_astar: a cheaper entry pushed earlier in this
+                // block means target's label is not final yet.
+                if state.heap.min_distance().is_some_and(|m| m < d) {
+                    state.heap.decrease_key(target, d);
                     continue;
                 }
+                let mut path = vec![target];
+                let mut cur = target;
+                while cur != source {
+                    cur = pred[cur];
+                    path.push(cur);
+                }
+                path.reverse();
+                return Ok(Some((dist[target], path)));
+            }
 
-                relax_edges(graph, weights, enabled, u, dist, pred, &mut state.heap);
+            let (start, _end) = graph.edge_range(u);
+            for (eid, &v) in graph.neighbors(u).iter().enumerate() {
+                let edge_idx = start + eid;
+                if let Some(enabled_mask) = enabled {
+                    if !enabled_mask[edge_idx] {
+                        continue;
+                    }
+                }
+
+                let w = weights[edge_idx];
+                let new_dist = dist[u] + w;
+                if new_dist < dist[v] {
+                    dist[v] = new_dist;
+                    pred[v] = u;
+                    state.heap.push(v, new_dist);
+                }
             }
         }
     }
-    
-    Ok((dist, pred))
+
+    Ok(None)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::block_heap::{IndexedBlockHeap, PairingBlockHeap};
     use crate::csr::CsrGraph;
 
+    #[test]
+    fn test_bmssp_sssp_with_frontier_matches_across_backends() {
+        // 5-vertex graph with a couple of alternate-length paths
+        let indptr = vec![0, 2, 3, 4, 5, 5];
+        let indices = vec![1, 2, 3, 4, 4];
+        let graph = CsrGraph::new(5, indptr, indices).unwrap();
+        let weights = vec![1.0f32, 4.0, 1.0, 1.0, 1.0];
+
+        let (expected, _) = bmssp_sssp_with_preds(&graph, &weights, 0, None).unwrap();
+
+        let mut fast = FastBlockHeap::new();
+        let (fast_dist, _) =
+            bmssp_sssp_with_frontier(&graph, &weights, 0, None, &mut fast).unwrap();
+
+        let mut indexed = IndexedBlockHeap::new();
+        let (indexed_dist, _) =
+            bmssp_sssp_with_frontier(&graph, &weights, 0, None, &mut indexed).unwrap();
+
+        let mut pairing = PairingBlockHeap::new();
+        let (pairing_dist, _) =
+            bmssp_sssp_with_frontier(&graph, &weights, 0, None, &mut pairing).unwrap();
+
+        let mut block = BlockHeap::new();
+        let (block_dist, _) =
+            bmssp_sssp_with_frontier(&graph, &weights, 0, None, &mut block).unwrap();
+
+        assert_eq!(fast_dist, expected);
+        assert_eq!(indexed_dist, expected);
+        assert_eq!(pairing_dist, expected);
+        assert_eq!(block_dist, expected);
+    }
+
     #[test]
     fn test_bmssp_simple() {
         let indptr = vec![0, 1, 1];
@@ -554,6 +1425,215 @@ mod tests {
         assert_eq!(dist[1], 1.0);
     }
     
+    #[test]
+    fn test_bmssp_multi_source_nearest_seed() {
+        // Two disjoint chains: 0->1->2 and 3->4, seeded from 0 and 3
+        let indptr = vec![0, 1, 2, 2, 3, 3];
+        let indices = vec![1, 2, 4];
+        let graph = CsrGraph::new(5, indptr, indices).unwrap();
+        let weights = vec![1.0f32, 1.0f32, 1.0f32];
+
+        let (dist, pred) = bmssp_sssp_with_preds_multi(&graph, &weights, &[0, 3], None, None).unwrap();
+
+        assert_eq!(dist[0], 0.0);
+        assert_eq!(dist[1], 1.0);
+        assert_eq!(dist[2], 2.0);
+        assert_eq!(dist[3], 0.0);
+        assert_eq!(dist[4], 1.0);
+
+        assert_eq!(pred[0], 0);
+        assert_eq!(pred[3], 3);
+        assert_eq!(pred[4], 3);
+    }
+
+    #[test]
+    fn test_bmssp_multi_source_with_initial_dist() {
+        // Single chain 0->1->2, but seed 1 directly with a head start so it
+        // wins over the path coming from seed 0.
+        let indptr = vec![0, 1, 2, 2];
+        let indices = vec![1, 2];
+        let graph = CsrGraph::new(3, indptr, indices).unwrap();
+        let weights = vec![10.0f32, 1.0f32];
+
+        let initial = vec![0.0f32, 0.0f32];
+        let (dist, pred) =
+            bmssp_sssp_with_preds_multi(&graph, &weights, &[0, 1], Some(&initial), None).unwrap();
+
+        assert_eq!(dist[0], 0.0);
+        assert_eq!(dist[1], 0.0);
+        assert_eq!(dist[2], 1.0);
+        assert_eq!(pred[1], 1);
+        assert_eq!(pred[2], 1);
+    }
+
+    #[test]
+    fn test_bmssp_multi_source_owner_disjoint_regions() {
+        // Two disjoint chains: 0->1->2 and 3->4, seeded from 0 and 3.
+        // Every vertex should be labeled with the seed it was reached from.
+        let indptr = vec![0, 1, 2, 2, 3, 3];
+        let indices = vec![1, 2, 4];
+        let graph = CsrGraph::new(5, indptr, indices).unwrap();
+        let weights = vec![1.0f32, 1.0f32, 1.0f32];
+
+        let (dist, pred, owner) =
+            bmssp_multi_source(&graph, &weights, &[(0, 0.0), (3, 0.0)], None).unwrap();
+
+        assert_eq!(dist, vec![0.0, 1.0, 2.0, 0.0, 1.0]);
+        assert_eq!(pred, vec![0, 0, 1, 3, 3]);
+        assert_eq!(owner, vec![0, 0, 0, 3, 3]);
+    }
+
+    #[test]
+    fn test_bmssp_multi_source_owner_weighted_offset_flips_ownership() {
+        // Single chain 0->1->2, both ends seeded. Seed 2 starts with a large
+        // enough offset that seed 0 reaches vertex 2 first despite being
+        // farther away in plain graph distance.
+        let indptr = vec![0, 1, 2, 2];
+        let indices = vec![1, 2];
+        let graph = CsrGraph::new(3, indptr, indices).unwrap();
+        let weights = vec![1.0f32, 1.0f32];
+
+        let (dist, pred, owner) =
+            bmssp_multi_source(&graph, &weights, &[(0, 0.0), (2, 10.0)], None).unwrap();
+
+        assert_eq!(dist, vec![0.0, 1.0, 2.0]);
+        assert_eq!(pred, vec![0, 0, 1]);
+        assert_eq!(owner, vec![0, 0, 0]);
+    }
+
+    #[test]
+    fn test_bmssp_multi_source_within_bound_disconnected() {
+        // Two disjoint chains: 0->1->2 and 3->4, seeded from 0 and 3.
+        // Vertex 2 sits right at the bound and must stay unreached.
+        let indptr = vec![0, 1, 2, 2, 3, 3];
+        let indices = vec![1, 2, 4];
+        let graph = CsrGraph::new(5, indptr, indices).unwrap();
+        let weights = vec![1.0f32, 1.0f32, 1.0f32];
+
+        let (dist, pred) =
+            bmssp_multi_source_within_bound(&graph, &weights, &[(0, 0.0), (3, 0.0)], 2.0, None)
+                .unwrap();
+
+        assert_eq!(dist[0], 0.0);
+        assert_eq!(dist[1], 1.0);
+        assert!(dist[2].is_infinite());
+        assert_eq!(dist[3], 0.0);
+        assert_eq!(dist[4], 1.0);
+        assert_eq!(pred[1], 0);
+        assert_eq!(pred[2], usize::MAX);
+    }
+
+    #[test]
+    fn test_bmssp_multi_source_within_bound_enabled_mask() {
+        // Chain 0 -> 1 -> 2; disabling the 1->2 edge must keep 2 unreached
+        // even though it would otherwise fall within the bound.
+        let indptr = vec![0, 1, 2, 2];
+        let indices = vec![1, 2];
+        let graph = CsrGraph::new(3, indptr, indices).unwrap();
+        let weights = vec![1.0f32, 1.0f32];
+        let enabled = vec![true, false];
+
+        let (dist, pred) = bmssp_multi_source_within_bound(
+            &graph,
+            &weights,
+            &[(0, 0.0)],
+            10.0,
+            Some(&enabled),
+        )
+        .unwrap();
+
+        assert_eq!(dist[0], 0.0);
+        assert_eq!(dist[1], 1.0);
+        assert!(dist[2].is_infinite());
+        assert_eq!(pred[2], usize::MAX);
+    }
+
+    #[test]
+    fn test_bmssp_astar_zero_heuristic_matches_dijkstra() {
+        // Chain: 0 -> 1 -> 2 -> 3, each weight 1.0. A zero heuristic must
+        // degrade exactly to plain Dijkstra/BMSSP.
+        let indptr = vec![0, 1, 2, 3, 3];
+        let indices = vec![1, 2, 3];
+        let graph = CsrGraph::new(4, indptr, indices).unwrap();
+        let weights = vec![1.0f32, 1.0f32, 1.0f32];
+
+        let (dist, path) = bmssp_astar(&graph, &weights, 0, 3, |_| 0.0f32, None)
+            .unwrap()
+            .unwrap();
+        assert_eq!(dist, 3.0);
+        assert_eq!(path, vec![0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn test_bmssp_astar_picks_shortest_of_two_routes() {
+        // Diamond: 0 -> 1 -> 3 (cost 1+5=6) and 0 -> 2 -> 3 (cost 2+1=3).
+        let indptr = vec![0, 2, 3, 4, 4];
+        let indices = vec![1, 2, 3, 3];
+        let graph = CsrGraph::new(4, indptr, indices).unwrap();
+        let weights = vec![1.0f32, 2.0, 5.0, 1.0];
+
+        let (dist, path) = bmssp_astar(&graph, &weights, 0, 3, |_| 0.0f32, None)
+            .unwrap()
+            .unwrap();
+        assert_eq!(dist, 3.0);
+        assert_eq!(path, vec![0, 2, 3]);
+    }
+
+    #[test]
+    fn test_bmssp_astar_unreachable_target() {
+        let indptr = vec![0, 1, 1, 1];
+        let indices = vec![1];
+        let graph = CsrGraph::new(3, indptr, indices).unwrap();
+        let weights = vec![1.0f32];
+
+        let result = bmssp_astar(&graph, &weights, 0, 2, |_| 0.0f32, None).unwrap();
+        assert!(result.is_none());
+    }
+
+    #[test]
+    fn test_bmssp_tolerant_matches_exact_on_chain() {
+        // Chain: 0 -> 1 -> 2 -> 3 -> 4, each weight 1.0
+        let indptr = vec![0, 1, 2, 3, 4, 4];
+        let indices = vec![1, 2, 3, 4];
+        let graph = CsrGraph::new(5, indptr, indices).unwrap();
+        let weights = vec![1.0f32, 1.0f32, 1.0f32, 1.0f32];
+
+        let (dist, pred) =
+            bmssp_sssp_with_preds_tolerant(&graph, &weights, 0, None, 0.001).unwrap();
+
+        assert_eq!(dist, vec![0.0, 1.0, 2.0, 3.0, 4.0]);
+        assert_eq!(pred, vec![0, 0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn test_bmssp_tolerant_disconnected() {
+        let indptr = vec![0, 1, 1, 1];
+        let indices = vec![1];
+        let graph = CsrGraph::new(3, indptr, indices).unwrap();
+        let weights = vec![1.0f32];
+
+        let (dist, _) = bmssp_sssp_with_preds_tolerant(&graph, &weights, 0, None, 0.01).unwrap();
+        assert_eq!(dist[0], 0.0);
+        assert_eq!(dist[1], 1.0);
+        assert!(dist[2].is_infinite());
+    }
+
+    #[test]
+    fn test_bmssp_sssp_dag_tied_routes_both_counted() {
+        // Diamond with equal-cost routes 0->1->3 and 0->2->3 (cost 2 each).
+        let indptr = vec![0, 2, 3, 4, 4];
+        let indices = vec![1, 2, 3, 3];
+        let graph = CsrGraph::new(4, indptr, indices).unwrap();
+        let weights = vec![1.0f32, 1.0, 1.0, 1.0];
+
+        let (dist, preds, sigma) = bmssp_sssp_dag(&graph, &weights, 0, None, 1e-6).unwrap();
+        assert_eq!(dist[3], 2.0);
+        assert_eq!(sigma[3], 2);
+        let mut via = preds[3].clone();
+        via.sort_unstable();
+        assert_eq!(via, vec![1, 2]);
+    }
+
     #[test]
     fn test_bmssp_with_preds() {
         let indptr = vec![0, 2, 3, 3];
@@ -724,13 +1804,13 @@ mod tests {
         
         // Compare results
         assert_eq!(dist_regular.len(), dist_state.len());
-        for i in 0..dist_regular.len() {
-            assert!((dist_regular[i] - dist_state[i]).abs() < 1e-6);
+        for (a, b) in dist_regular.iter().zip(dist_state) {
+            assert!((a - b).abs() < 1e-6);
         }
-        
+
         assert_eq!(pred_regular.len(), pred_state.len());
-        for i in 0..pred_regular.len() {
-            assert_eq!(pred_regular[i], pred_state[i]);
+        for (a, b) in pred_regular.iter().zip(pred_state) {
+            assert_eq!(a, b);
         }
     }
     
@@ -784,4 +1864,99 @@ mod tests {
         assert_eq!(dist[2], 2.0);
         assert_eq!(dist[3], 2.0);
     }
+
+    #[test]
+    fn test_bmssp_sssp_to_target_matches_full_sssp() {
+        // Diamond: 0->1->3 (cost 2), 0->2->3 (cost 11); the cheap route wins.
+        let indptr = vec![0, 2, 3, 4, 4];
+        let indices = vec![1, 2, 3, 3];
+        let graph = CsrGraph::new(4, indptr, indices).unwrap();
+        let weights = vec![1.0f32, 1.0, 1.0, 10.0];
+
+        let (distance, path) = bmssp_sssp_to_target(&graph, &weights, 0, 3, None)
+            .unwrap()
+            .unwrap();
+        assert_eq!(distance, 2.0);
+        assert_eq!(path, vec![0, 1, 3]);
+
+        let full = bmssp_sssp(&graph, &weights, 0, None).unwrap();
+        assert_eq!(distance, full[3]);
+    }
+
+    #[test]
+    fn test_bmssp_sssp_to_target_unreachable_is_none() {
+        let indptr = vec![0, 1, 1, 1];
+        let indices = vec![1];
+        let graph = CsrGraph::new(3, indptr, indices).unwrap();
+        let weights = vec![1.0f32];
+
+        assert!(bmssp_sssp_to_target(&graph, &weights, 0, 2, None)
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    fn test_bmssp_sssp_to_target_with_state_reused_across_queries() {
+        let indptr = vec![0, 2, 3, 4, 4];
+        let indices = vec![1, 2, 3, 3];
+        let graph = CsrGraph::new(4, indptr, indices).unwrap();
+        let weights = vec![1.0f32, 1.0, 1.0, 10.0];
+
+        let mut state = BmsspState::new(4);
+        let (d1, _) = bmssp_sssp_to_target_with_state(&mut state, &graph, &weights, 0, 3, None)
+            .unwrap()
+            .unwrap();
+        assert_eq!(d1, 2.0);
+
+        let (d2, path2) =
+            bmssp_sssp_to_target_with_state(&mut state, &graph, &weights, 0, 2, None)
+                .unwrap()
+                .unwrap();
+        assert_eq!(d2, 1.0);
+        assert_eq!(path2, vec![0, 2]);
+    }
+
+    #[test]
+    fn test_bmssp_sssp_multi_rejects_out_of_range_source() {
+        let indptr = vec![0, 1, 1];
+        let indices = vec![1];
+        let graph = CsrGraph::new(2, indptr, indices).unwrap();
+        let weights = vec![1.0f32];
+
+        assert!(bmssp_sssp_multi(&graph, &weights, &[0, 5], None, None).is_err());
+    }
+
+    #[test]
+    fn test_bmssp_sssp_multi_rejects_mismatched_init_dist_len() {
+        let indptr = vec![0, 1, 1];
+        let indices = vec![1];
+        let graph = CsrGraph::new(2, indptr, indices).unwrap();
+        let weights = vec![1.0f32];
+
+        let init = vec![0.0f32, 1.0, 2.0];
+        assert!(bmssp_sssp_multi(&graph, &weights, &[0, 1], Some(&init), None).is_err());
+    }
+
+    #[test]
+    fn test_bmssp_sssp_with_preds_and_state_masked_disables_edges() {
+        // Diamond: 0->1->3 (cost 2), 0->2->3 (cost 11).
+        let indptr = vec![0, 2, 3, 4, 4];
+        let indices = vec![1, 2, 3, 3];
+        let graph = CsrGraph::new(4, indptr, indices).unwrap();
+        let weights = vec![1.0f32, 1.0, 1.0, 10.0];
+
+        let mut state = BmsspState::new(4);
+        state.set_enabled_mask(EnabledMask::new(4, true));
+
+        let (dist, _) =
+            bmssp_sssp_with_preds_and_state_masked(&mut state, &graph, &weights, 0).unwrap();
+        assert_eq!(dist[3], 2.0);
+
+        // Disable the 0 -> 1 edge without rebuilding the mask; the heavier
+        // route through vertex 2 should win instead.
+        state.set_edge_enabled(0, false);
+        let (dist, _) =
+            bmssp_sssp_with_preds_and_state_masked(&mut state, &graph, &weights, 0).unwrap();
+        assert_eq!(dist[3], 11.0);
+    }
 }
@@ -0,0 +1,198 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+use num_traits::Float;
+
+use crate::bmssp::bmssp_sssp;
+use crate::csr::CsrGraph;
+use crate::error::Result;
+use crate::ordered_float::OrderedFloat;
+use crate::validation;
+
+/// Single-source Dijkstra that, beyond `dist`, tracks everything Brandes'
+/// algorithm needs: `sigma[v]` (the number of distinct shortest paths from
+/// `s` to `v`, saturating to avoid overflow on dense graphs) and `preds[v]`
+/// (every `u` with `dist[u] + w(u, v) == dist[v]`), plus `order`, the
+/// vertices in the sequence they were finalized — non-decreasing distance
+/// from `s`, which is exactly the order Brandes' backward accumulation
+/// pass needs to walk in reverse.
+fn brandes_single_source<T>(
+    graph: &CsrGraph,
+    weights: &[T],
+    source: usize,
+    enabled: Option<&[bool]>,
+) -> (Vec<T>, Vec<u64>, Vec<Vec<usize>>, Vec<usize>)
+where
+    T: Float + Copy,
+{
+    let n = graph.num_vertices();
+    let mut dist = vec![T::infinity(); n];
+    let mut sigma = vec![0u64; n];
+    let mut preds: Vec<Vec<usize>> = vec![Vec::new(); n];
+    let mut settled = vec![false; n];
+    let mut order = Vec::with_capacity(n);
+
+    dist[source] = T::zero();
+    sigma[source] = 1;
+
+    let mut heap: BinaryHeap<Reverse<(OrderedFloat<T>, usize)>> = BinaryHeap::new();
+    heap.push(Reverse((OrderedFloat(T::zero()), source)));
+
+    while let Some(Reverse((OrderedFloat(d), u))) = heap.pop() {
+        if settled[u] {
+            continue;
+        }
+        settled[u] = true;
+        order.push(u);
+
+        let (start, _end) = graph.edge_range(u);
+        for (eid, &v) in graph.neighbors(u).iter().enumerate() {
+            if settled[v] {
+                continue;
+            }
+            let edge_idx = start + eid;
+            if let Some(mask) = enabled {
+                if !mask[edge_idx] {
+                    continue;
+                }
+            }
+
+            let candidate = d + weights[edge_idx];
+            if candidate < dist[v] {
+                dist[v] = candidate;
+                sigma[v] = sigma[u];
+                preds[v].clear();
+                preds[v].push(u);
+                heap.push(Reverse((OrderedFloat(candidate), v)));
+            } else if candidate == dist[v] {
+                sigma[v] = sigma[v].saturating_add(sigma[u]);
+                preds[v].push(u);
+            }
+        }
+    }
+
+    (dist, sigma, preds, order)
+}
+
+/// Shortest-path betweenness centrality via Brandes' algorithm
+///
+/// Runs [`brandes_single_source`] from every vertex, then for each source
+/// pops its settle order in reverse, accumulating the dependency
+/// `delta[u] += (sigma[u] / sigma[w]) * (1 + delta[w])` for every `u` in
+/// `w`'s predecessor set and folding `delta[w]` into `w`'s running score.
+/// Scores are over the graph exactly as given (directed); for an
+/// undirected graph represented as CSR with both directions present,
+/// halve the result to match the usual undirected convention.
+pub fn betweenness<T>(graph: &CsrGraph, weights: &[T], enabled: Option<&[bool]>) -> Result<Vec<T>>
+where
+    T: Float + Copy,
+{
+    validation::validate_weights_len(graph, weights.len())?;
+    if let Some(mask) = enabled {
+        validation::validate_enabled_mask(graph.num_edges(), mask)?;
+    }
+
+    let n = graph.num_vertices();
+    let mut score = vec![T::zero(); n];
+
+    for s in 0..n {
+        let (_dist, sigma, preds, order) = brandes_single_source(graph, weights, s, enabled);
+        let mut delta = vec![T::zero(); n];
+
+        for &w in order.iter().rev() {
+            for &u in &preds[w] {
+                let ratio = T::from(sigma[u]).unwrap() / T::from(sigma[w]).unwrap();
+                delta[u] = delta[u] + ratio * (T::one() + delta[w]);
+            }
+            if w != s {
+                score[w] = score[w] + delta[w];
+            }
+        }
+    }
+
+    Ok(score)
+}
+
+/// Closeness centrality, Wasserman-Faust style for disconnected graphs
+///
+/// For each vertex `v`, runs a full SSSP and sets
+/// `C(v) = (reachable - 1) / sum_of_finite_distances`, where `reachable`
+/// counts vertices (including `v`) with a finite distance from `v`. Vertices
+/// that reach no one else score `0` rather than dividing by zero, and
+/// unreachable vertices simply don't contribute to the sum — so closeness
+/// stays meaningful on a disconnected graph instead of being dominated by
+/// infinite distances.
+pub fn closeness<T>(graph: &CsrGraph, weights: &[T], enabled: Option<&[bool]>) -> Result<Vec<T>>
+where
+    T: Float + Copy + Send + Sync + 'static,
+{
+    validation::validate_weights_len(graph, weights.len())?;
+    if let Some(mask) = enabled {
+        validation::validate_enabled_mask(graph.num_edges(), mask)?;
+    }
+
+    let n = graph.num_vertices();
+    let mut result = vec![T::zero(); n];
+
+    for (v, out) in result.iter_mut().enumerate() {
+        let dist = bmssp_sssp(graph, weights, v, enabled)?;
+        let mut reachable = 0usize;
+        let mut sum = T::zero();
+        for &d in &dist {
+            if d.is_finite() {
+                reachable += 1;
+                sum = sum + d;
+            }
+        }
+        if reachable > 1 && sum > T::zero() {
+            *out = T::from(reachable - 1).unwrap() / sum;
+        }
+    }
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_closeness_on_chain() {
+        // 0 -> 1 -> 2, weight 1 each: from 0, sum=1+2=3, reachable=3 -> 2/3.
+        let indptr = vec![0, 1, 2, 2];
+        let indices = vec![1, 2];
+        let graph = CsrGraph::new(3, indptr, indices).unwrap();
+        let weights = vec![1.0f32, 1.0];
+
+        let c = closeness(&graph, &weights, None).unwrap();
+        assert!((c[0] - 2.0 / 3.0).abs() < 1e-6);
+        assert_eq!(c[2], 0.0);
+    }
+
+    #[test]
+    fn test_betweenness_on_path_through_middle_vertex() {
+        // 0 -> 1 -> 2 and 0 -> 2 directly, but the direct edge is heavier,
+        // so every shortest path 0->2 routes through 1, giving it nonzero
+        // betweenness while the endpoints stay at zero.
+        let indptr = vec![0, 2, 3, 3];
+        let indices = vec![1, 2, 2];
+        let graph = CsrGraph::new(3, indptr, indices).unwrap();
+        let weights = vec![1.0f32, 10.0, 1.0];
+
+        let bc = betweenness(&graph, &weights, None).unwrap();
+        assert_eq!(bc[0], 0.0);
+        assert_eq!(bc[2], 0.0);
+        assert!(bc[1] > 0.0);
+    }
+
+    #[test]
+    fn test_betweenness_isolated_vertex_has_zero_score() {
+        let indptr = vec![0, 1, 1, 1];
+        let indices = vec![1];
+        let graph = CsrGraph::new(3, indptr, indices).unwrap();
+        let weights = vec![1.0f32];
+
+        let bc = betweenness(&graph, &weights, None).unwrap();
+        assert_eq!(bc, vec![0.0, 0.0, 0.0]);
+    }
+}
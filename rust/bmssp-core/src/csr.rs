@@ -119,6 +119,528 @@ impl CsrGraph {
     pub fn edge_range(&self, u: usize) -> (usize, usize) {
         (self.indptr[u], self.indptr[u + 1])
     }
+
+    /// Build the reverse (transposed) graph in O(n + m) via a counting sort
+    ///
+    /// Returns the transposed graph along with an edge map of the same
+    /// length as `indices`: `edge_map[i]` is the original edge index that
+    /// ended up at position `i` in the transposed graph's `indices` array.
+    /// Callers can use this to build a correspondingly reversed weights (or
+    /// enabled-mask) array via `edge_map.iter().map(|&i| weights[i])`.
+    pub fn transpose(&self) -> (CsrGraph, Vec<usize>) {
+        let m = self.indices.len();
+
+        let mut in_degree = vec![0usize; self.n];
+        for &v in &self.indices {
+            in_degree[v] += 1;
+        }
+
+        let mut indptr = vec![0usize; self.n + 1];
+        for i in 0..self.n {
+            indptr[i + 1] = indptr[i] + in_degree[i];
+        }
+
+        let mut indices = vec![0usize; m];
+        let mut edge_map = vec![0usize; m];
+        let mut cursor = indptr.clone();
+        for u in 0..self.n {
+            let (start, end) = self.edge_range(u);
+            for edge_idx in start..end {
+                let v = self.indices[edge_idx];
+                let pos = cursor[v];
+                indices[pos] = u;
+                edge_map[pos] = edge_idx;
+                cursor[v] += 1;
+            }
+        }
+
+        (
+            CsrGraph {
+                n: self.n,
+                indptr,
+                indices,
+            },
+            edge_map,
+        )
+    }
+
+    /// Build a CSR graph directly from an unsorted edge list, using rayon
+    /// to parallelize the expensive parts of the topology build
+    ///
+    /// `edges` need not be sorted or grouped by source. When `directed` is
+    /// `false`, both `(u, v)` and `(v, u)` are emitted for every edge. The
+    /// out-degree count (the part that scales with the number of edges) and
+    /// the undirected doubling are done with rayon; `indptr`'s prefix sum is
+    /// an O(n) sequential scan (negligible next to the edge-count work) and
+    /// the final scatter into `indices`/`weights` is a single sequential
+    /// pass over already-computed, guaranteed-unique slot offsets — so
+    /// there's no need for unsafe concurrent writes into the same buffer.
+    ///
+    /// Returns the graph plus the `f32` weight array aligned to `indices`.
+    pub fn from_edges(
+        num_nodes: usize,
+        edges: &[(u32, u32, f32)],
+        directed: bool,
+    ) -> Result<(CsrGraph, Vec<f32>)> {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        #[cfg(feature = "parallel")]
+        let expanded: Vec<(usize, usize, f32)> = {
+            use rayon::prelude::*;
+            edges
+                .par_iter()
+                .flat_map_iter(|&(u, v, w)| {
+                    let (u, v) = (u as usize, v as usize);
+                    if directed {
+                        vec![(u, v, w)].into_iter()
+                    } else {
+                        vec![(u, v, w), (v, u, w)].into_iter()
+                    }
+                })
+                .collect()
+        };
+
+        #[cfg(not(feature = "parallel"))]
+        let expanded: Vec<(usize, usize, f32)> = edges
+            .iter()
+            .flat_map(|&(u, v, w)| {
+                let (u, v) = (u as usize, v as usize);
+                if directed {
+                    vec![(u, v, w)]
+                } else {
+                    vec![(u, v, w), (v, u, w)]
+                }
+            })
+            .collect();
+
+        let degree: Vec<AtomicUsize> = (0..num_nodes).map(|_| AtomicUsize::new(0)).collect();
+        #[cfg(feature = "parallel")]
+        {
+            use rayon::prelude::*;
+            expanded.par_iter().for_each(|&(u, _, _)| {
+                degree[u].fetch_add(1, Ordering::Relaxed);
+            });
+        }
+        #[cfg(not(feature = "parallel"))]
+        for &(u, _, _) in &expanded {
+            degree[u].fetch_add(1, Ordering::Relaxed);
+        }
+
+        let mut indptr = vec![0usize; num_nodes + 1];
+        for i in 0..num_nodes {
+            indptr[i + 1] = indptr[i] + degree[i].load(Ordering::Relaxed);
+        }
+
+        let cursor: Vec<AtomicUsize> = indptr[..num_nodes]
+            .iter()
+            .map(|&start| AtomicUsize::new(start))
+            .collect();
+
+        #[cfg(feature = "parallel")]
+        let slots: Vec<usize> = {
+            use rayon::prelude::*;
+            expanded
+                .par_iter()
+                .map(|&(u, _, _)| cursor[u].fetch_add(1, Ordering::Relaxed))
+                .collect()
+        };
+        #[cfg(not(feature = "parallel"))]
+        let slots: Vec<usize> = expanded
+            .iter()
+            .map(|&(u, _, _)| cursor[u].fetch_add(1, Ordering::Relaxed))
+            .collect();
+
+        let mut indices = vec![0usize; expanded.len()];
+        let mut weights = vec![0f32; expanded.len()];
+        for (&pos, &(_, v, w)) in slots.iter().zip(expanded.iter()) {
+            indices[pos] = v;
+            weights[pos] = w;
+        }
+
+        let graph = CsrGraph::new(num_nodes, indptr, indices)?;
+        Ok((graph, weights))
+    }
+
+    /// Strongly connected components via iterative Tarjan's algorithm
+    ///
+    /// Returns a component id per vertex (`result[v]` is the 0-indexed id
+    /// of the SCC containing `v`; ids have no particular ordering meaning
+    /// beyond grouping). The DFS uses an explicit stack of `(vertex,
+    /// next_edge_cursor)` frames rather than recursion, so it doesn't blow
+    /// the call stack on deep graphs.
+    pub fn strongly_connected_components(&self) -> Vec<usize> {
+        let n = self.n;
+        let mut index = vec![usize::MAX; n];
+        let mut lowlink = vec![0usize; n];
+        let mut on_stack = vec![false; n];
+        let mut comp = vec![usize::MAX; n];
+        let mut scc_stack: Vec<usize> = Vec::new();
+        let mut next_index = 0usize;
+        let mut next_comp = 0usize;
+
+        for start in 0..n {
+            if index[start] != usize::MAX {
+                continue;
+            }
+
+            let mut work: Vec<(usize, usize)> = vec![(start, self.indptr[start])];
+            index[start] = next_index;
+            lowlink[start] = next_index;
+            next_index += 1;
+            scc_stack.push(start);
+            on_stack[start] = true;
+
+            while let Some(&mut (u, ref mut edge_cursor)) = work.last_mut() {
+                let end = self.indptr[u + 1];
+                if *edge_cursor < end {
+                    let v = self.indices[*edge_cursor];
+                    *edge_cursor += 1;
+                    if index[v] == usize::MAX {
+                        index[v] = next_index;
+                        lowlink[v] = next_index;
+                        next_index += 1;
+                        scc_stack.push(v);
+                        on_stack[v] = true;
+                        work.push((v, self.indptr[v]));
+                    } else if on_stack[v] {
+                        lowlink[u] = lowlink[u].min(index[v]);
+                    }
+                } else {
+                    work.pop();
+                    if let Some(&mut (parent, _)) = work.last_mut() {
+                        lowlink[parent] = lowlink[parent].min(lowlink[u]);
+                    }
+                    if lowlink[u] == index[u] {
+                        loop {
+                            let w = scc_stack.pop().expect("u's own SCC is still on the stack");
+                            on_stack[w] = false;
+                            comp[w] = next_comp;
+                            if w == u {
+                                break;
+                            }
+                        }
+                        next_comp += 1;
+                    }
+                }
+            }
+        }
+
+        comp
+    }
+
+    /// Build the condensation graph: one vertex per strongly connected
+    /// component, with a deduplicated edge `cu -> cv` whenever some edge
+    /// of `self` crosses from component `cu` to a different component
+    /// `cv`. Returns the condensed graph alongside the vertex-to-component
+    /// mapping from [`strongly_connected_components`](Self::strongly_connected_components).
+    pub fn condense(&self) -> (CsrGraph, Vec<usize>) {
+        let comp = self.strongly_connected_components();
+        let num_components = comp.iter().copied().max().map_or(0, |m| m + 1);
+
+        let mut edge_set: std::collections::BTreeSet<(usize, usize)> = std::collections::BTreeSet::new();
+        for u in 0..self.n {
+            let (start, end) = self.edge_range(u);
+            for &v in &self.indices[start..end] {
+                let (cu, cv) = (comp[u], comp[v]);
+                if cu != cv {
+                    edge_set.insert((cu, cv));
+                }
+            }
+        }
+
+        let mut indptr = vec![0usize; num_components + 1];
+        for &(cu, _) in &edge_set {
+            indptr[cu + 1] += 1;
+        }
+        for i in 0..num_components {
+            indptr[i + 1] += indptr[i];
+        }
+
+        let mut indices = vec![0usize; edge_set.len()];
+        let mut cursor = indptr.clone();
+        for &(cu, cv) in &edge_set {
+            let pos = cursor[cu];
+            indices[pos] = cv;
+            cursor[cu] += 1;
+        }
+
+        let graph = CsrGraph::new(num_components, indptr, indices)
+            .expect("condensation indptr/indices are well-formed by construction");
+        (graph, comp)
+    }
+
+    /// Build a CSR graph from any petgraph graph type (`Graph`,
+    /// `StableGraph`, `GraphMap`, ...) via petgraph's generic `visit`
+    /// traits, so callers holding one of those don't have to hand-flatten
+    /// adjacency into `indptr`/`indices`/weights themselves.
+    ///
+    /// Returns the graph, an `f32` weight array aligned to `indices` (ready
+    /// to hand straight to [`validate_weights_len`](crate::validation::validate_weights_len)'s
+    /// contract), and a `Vec<G::NodeId>` mapping vertex id back to the
+    /// caller's original node handle (`node_id_for[v]` is the petgraph
+    /// `NodeId`/`NodeIndex` of vertex `v`) so results from `bmssp_sssp` and
+    /// friends can be mapped back onto the source graph.
+    ///
+    /// Only available with the `petgraph` crate feature enabled, so the
+    /// core stays dependency-free otherwise.
+    #[cfg(feature = "petgraph")]
+    pub fn from_petgraph<G>(graph: G) -> Result<(CsrGraph, Vec<f32>, Vec<G::NodeId>)>
+    where
+        G: petgraph::visit::IntoNodeIdentifiers
+            + petgraph::visit::IntoEdgeReferences
+            + petgraph::visit::NodeIndexable,
+        G::EdgeWeight: Copy + Into<f32>,
+    {
+        use petgraph::visit::EdgeRef;
+
+        let node_ids: Vec<G::NodeId> = graph.node_identifiers().collect();
+        let n = node_ids.len();
+
+        let mut degree = vec![0usize; n];
+        let edges: Vec<(usize, usize, f32)> = graph
+            .edge_references()
+            .map(|e| {
+                let u = graph.to_index(e.source());
+                let v = graph.to_index(e.target());
+                degree[u] += 1;
+                (u, v, (*e.weight()).into())
+            })
+            .collect();
+
+        let mut indptr = vec![0usize; n + 1];
+        for i in 0..n {
+            indptr[i + 1] = indptr[i] + degree[i];
+        }
+
+        let mut cursor = indptr.clone();
+        let mut indices = vec![0usize; edges.len()];
+        let mut weights = vec![0f32; edges.len()];
+        for &(u, v, w) in &edges {
+            let pos = cursor[u];
+            indices[pos] = v;
+            weights[pos] = w;
+            cursor[u] += 1;
+        }
+
+        let graph = CsrGraph::new(n, indptr, indices)?;
+        Ok((graph, weights, node_ids))
+    }
+}
+
+/// The three operations the shortest-path algorithms in this crate actually
+/// need from a graph: how many vertices it has, where a vertex's edges live,
+/// and which vertices they lead to
+///
+/// [`CsrGraph`] implements this directly; [`UndirectedCsrGraph`] adapts a
+/// directed [`CsrGraph`] by presenting each stored edge from both endpoints.
+/// Algorithms written against `G: ShortestPathGraph` instead of a concrete
+/// `&CsrGraph` run unchanged over either representation.
+pub trait ShortestPathGraph {
+    /// Number of vertices
+    fn num_vertices(&self) -> usize;
+    /// Range of edge indices for a vertex, as `(start, end)`
+    fn edge_range(&self, u: usize) -> (usize, usize);
+    /// Outgoing neighbors of a vertex
+    fn neighbors(&self, u: usize) -> &[usize];
+}
+
+impl ShortestPathGraph for CsrGraph {
+    #[inline]
+    fn num_vertices(&self) -> usize {
+        self.num_vertices()
+    }
+
+    #[inline]
+    fn edge_range(&self, u: usize) -> (usize, usize) {
+        self.edge_range(u)
+    }
+
+    #[inline]
+    fn neighbors(&self, u: usize) -> &[usize] {
+        self.neighbors(u)
+    }
+}
+
+/// Undirected adapter over a directed [`CsrGraph`]: every stored edge
+/// `u -> v` is present in both directions, so [`ShortestPathGraph::neighbors`]
+/// sees every incident edge regardless of which endpoint it was originally
+/// stored from
+///
+/// Built with [`UndirectedCsrGraph::from_directed`], which also returns an
+/// edge map of the same length as the doubled `indices` array --
+/// `edge_map[i]` is the original edge index in the source graph whose
+/// weight applies to doubled edge `i` -- mirroring [`CsrGraph::transpose`]'s
+/// edge-map convention, so callers build a correspondingly-doubled weight
+/// array via `edge_map.iter().map(|&i| weights[i]).collect()` instead of
+/// hand-duplicating edges before ever reaching CSR construction.
+#[derive(Debug, Clone)]
+pub struct UndirectedCsrGraph {
+    inner: CsrGraph,
+}
+
+impl UndirectedCsrGraph {
+    /// Build the undirected adapter over `graph`, doubling every edge
+    pub fn from_directed(graph: &CsrGraph) -> (Self, Vec<usize>) {
+        let n = graph.num_vertices();
+        let m = graph.num_edges();
+
+        let mut degree = vec![0usize; n];
+        for u in 0..n {
+            let (start, end) = graph.edge_range(u);
+            degree[u] += end - start;
+            for &v in &graph.indices[start..end] {
+                degree[v] += 1;
+            }
+        }
+
+        let mut indptr = vec![0usize; n + 1];
+        for i in 0..n {
+            indptr[i + 1] = indptr[i] + degree[i];
+        }
+
+        let mut indices = vec![0usize; 2 * m];
+        let mut edge_map = vec![0usize; 2 * m];
+        let mut cursor = indptr.clone();
+
+        for u in 0..n {
+            let (start, end) = graph.edge_range(u);
+            for edge_idx in start..end {
+                let v = graph.indices[edge_idx];
+
+                let fwd = cursor[u];
+                indices[fwd] = v;
+                edge_map[fwd] = edge_idx;
+                cursor[u] += 1;
+
+                let rev = cursor[v];
+                indices[rev] = u;
+                edge_map[rev] = edge_idx;
+                cursor[v] += 1;
+            }
+        }
+
+        let inner = CsrGraph { n, indptr, indices };
+        (Self { inner }, edge_map)
+    }
+
+    /// Borrow the underlying doubled [`CsrGraph`]
+    pub fn inner(&self) -> &CsrGraph {
+        &self.inner
+    }
+}
+
+impl ShortestPathGraph for UndirectedCsrGraph {
+    #[inline]
+    fn num_vertices(&self) -> usize {
+        self.inner.num_vertices()
+    }
+
+    #[inline]
+    fn edge_range(&self, u: usize) -> (usize, usize) {
+        self.inner.edge_range(u)
+    }
+
+    #[inline]
+    fn neighbors(&self, u: usize) -> &[usize] {
+        self.inner.neighbors(u)
+    }
+}
+
+/// Borrowed, zero-copy view over CSR arrays stored as `i64` (numpy's native
+/// index dtype), for callers that re-query the same graph many times and
+/// don't want to pay for a `Vec<usize>` conversion on every call.
+///
+/// Construct with [`CsrGraphView::new`], [`validate`](Self::validate) once,
+/// then either read through the view directly (`neighbors`/`edge_range`
+/// convert indices on the fly, without allocating) or call
+/// [`to_owned`](Self::to_owned) to materialize a [`CsrGraph`] once for reuse
+/// across many algorithm calls.
+#[derive(Debug, Clone, Copy)]
+pub struct CsrGraphView<'a> {
+    n: usize,
+    indptr: &'a [i64],
+    indices: &'a [i64],
+}
+
+impl<'a> CsrGraphView<'a> {
+    /// Wrap borrowed `indptr`/`indices` slices without copying
+    pub fn new(n: usize, indptr: &'a [i64], indices: &'a [i64]) -> Self {
+        Self { n, indptr, indices }
+    }
+
+    /// Validate the CSR structure without materializing an owned copy
+    pub fn validate(&self) -> Result<()> {
+        if self.indptr.len() != self.n + 1 {
+            return Err(BmsspError::InvalidGraph(format!(
+                "indptr length {} != n+1 ({})",
+                self.indptr.len(),
+                self.n + 1
+            )));
+        }
+
+        for i in 0..self.n {
+            if self.indptr[i] < 0 || self.indptr[i + 1] < 0 || self.indptr[i] > self.indptr[i + 1] {
+                return Err(BmsspError::InvalidGraph(format!(
+                    "indptr not monotonic at index {}: {} > {}",
+                    i, self.indptr[i], self.indptr[i + 1]
+                )));
+            }
+        }
+
+        for &idx in self.indices {
+            if idx < 0 || idx as usize >= self.n {
+                return Err(BmsspError::InvalidGraph(format!(
+                    "Index {} out of range (n={})",
+                    idx, self.n
+                )));
+            }
+        }
+
+        if let Some(&last) = self.indptr.last() {
+            if last as usize != self.indices.len() {
+                return Err(BmsspError::InvalidGraph(format!(
+                    "indptr[{}] = {} != indices.len() = {}",
+                    self.n,
+                    last,
+                    self.indices.len()
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Number of vertices
+    #[inline]
+    pub fn num_vertices(&self) -> usize {
+        self.n
+    }
+
+    /// Number of edges
+    #[inline]
+    pub fn num_edges(&self) -> usize {
+        self.indices.len()
+    }
+
+    /// Get the range of edge indices for a vertex
+    pub fn edge_range(&self, u: usize) -> (usize, usize) {
+        (self.indptr[u] as usize, self.indptr[u + 1] as usize)
+    }
+
+    /// Get outgoing neighbors of a vertex, converting indices lazily
+    pub fn neighbors(&self, u: usize) -> impl Iterator<Item = usize> + 'a {
+        let (start, end) = self.edge_range(u);
+        self.indices[start..end].iter().map(|&v| v as usize)
+    }
+
+    /// Materialize an owned [`CsrGraph`], paying the `usize` conversion cost
+    /// exactly once regardless of how many queries follow
+    pub fn to_owned(&self) -> Result<CsrGraph> {
+        self.validate()?;
+        let indptr = self.indptr.iter().map(|&x| x as usize).collect();
+        let indices = self.indices.iter().map(|&x| x as usize).collect();
+        CsrGraph::new(self.n, indptr, indices)
+    }
 }
 
 #[cfg(test)]
@@ -160,4 +682,190 @@ mod tests {
         let result = CsrGraph::new(2, indptr, indices);
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_transpose_basic() {
+        // 0 -> 1, 0 -> 2, 1 -> 2
+        let indptr = vec![0, 2, 3, 3];
+        let indices = vec![1, 2, 2];
+        let graph = CsrGraph::new(3, indptr, indices).unwrap();
+
+        let (rev, edge_map) = graph.transpose();
+        assert_eq!(rev.num_vertices(), 3);
+        assert_eq!(rev.num_edges(), 3);
+        assert_eq!(rev.neighbors(0), &[] as &[usize]);
+        assert_eq!(rev.neighbors(1), &[0]);
+        let mut into_2: Vec<usize> = rev.neighbors(2).to_vec();
+        into_2.sort_unstable();
+        assert_eq!(into_2, vec![0, 1]);
+
+        // edge_map must point back at valid original edge indices whose
+        // destination matches the transposed source's row.
+        for u in 0..rev.num_vertices() {
+            let (start, end) = rev.edge_range(u);
+            for pos in start..end {
+                let original_edge = edge_map[pos];
+                assert_eq!(graph.indices()[original_edge], u);
+            }
+        }
+    }
+
+    #[test]
+    fn test_undirected_csr_graph_sees_edges_from_both_endpoints() {
+        // 0 -> 1, 1 -> 2; undirected, vertex 1 should see both 0 and 2.
+        let indptr = vec![0, 1, 2, 2];
+        let indices = vec![1, 2];
+        let graph = CsrGraph::new(3, indptr, indices).unwrap();
+
+        let (undirected, edge_map) = UndirectedCsrGraph::from_directed(&graph);
+        assert_eq!(undirected.num_vertices(), 3);
+        assert_eq!(edge_map.len(), 2 * graph.num_edges());
+
+        let mut from_1 = undirected.neighbors(1).to_vec();
+        from_1.sort_unstable();
+        assert_eq!(from_1, vec![0, 2]);
+        assert_eq!(undirected.neighbors(0), &[1]);
+        assert_eq!(undirected.neighbors(2), &[1]);
+
+        // Every doubled edge's edge_map entry must point at an original
+        // edge whose (source, dest) pair matches {u, v} in either order.
+        let mut edge_source = vec![0usize; graph.num_edges()];
+        for w in 0..graph.num_vertices() {
+            let (start, end) = graph.edge_range(w);
+            for edge_idx in start..end {
+                edge_source[edge_idx] = w;
+            }
+        }
+
+        for u in 0..undirected.num_vertices() {
+            let (start, end) = undirected.edge_range(u);
+            for pos in start..end {
+                let v = undirected.neighbors(u)[pos - start];
+                let original_edge = edge_map[pos];
+                let (src, dst) = (edge_source[original_edge], graph.indices()[original_edge]);
+                assert!((u, v) == (src, dst) || (u, v) == (dst, src));
+            }
+        }
+    }
+
+    #[test]
+    fn test_transpose_roundtrip_preserves_edge_count() {
+        let indptr = vec![0, 1, 3, 4, 4];
+        let indices = vec![1, 0, 2, 1];
+        let graph = CsrGraph::new(4, indptr, indices).unwrap();
+
+        let (rev, edge_map) = graph.transpose();
+        assert_eq!(rev.num_edges(), graph.num_edges());
+        assert_eq!(edge_map.len(), graph.num_edges());
+
+        let (rev_rev, _) = rev.transpose();
+        assert_eq!(rev_rev.indptr(), graph.indptr());
+        let mut original_sorted = graph.indices().to_vec();
+        let mut roundtrip_sorted = rev_rev.indices().to_vec();
+        original_sorted.sort_unstable();
+        roundtrip_sorted.sort_unstable();
+        assert_eq!(original_sorted, roundtrip_sorted);
+    }
+
+    #[test]
+    fn test_from_edges_directed() {
+        let edges = vec![(0u32, 1u32, 1.0f32), (0, 2, 2.0), (1, 2, 3.0)];
+        let (graph, weights) = CsrGraph::from_edges(3, &edges, true).unwrap();
+
+        assert_eq!(graph.num_vertices(), 3);
+        assert_eq!(graph.num_edges(), 3);
+
+        let mut from_0: Vec<(usize, f32)> = {
+            let (start, end) = graph.edge_range(0);
+            (start..end).map(|i| (graph.indices()[i], weights[i])).collect()
+        };
+        from_0.sort_by_key(|&(v, _)| v);
+        assert_eq!(from_0, vec![(1, 1.0), (2, 2.0)]);
+    }
+
+    #[test]
+    fn test_from_edges_undirected_emits_both_directions() {
+        let edges = vec![(0u32, 1u32, 5.0f32)];
+        let (graph, weights) = CsrGraph::from_edges(2, &edges, false).unwrap();
+
+        assert_eq!(graph.num_edges(), 2);
+        assert_eq!(graph.neighbors(0), &[1]);
+        assert_eq!(graph.neighbors(1), &[0]);
+        assert_eq!(weights, vec![5.0, 5.0]);
+    }
+
+    #[test]
+    fn test_strongly_connected_components_cycle_and_tail() {
+        // 0 <-> 1, 1 <-> 2 (so 0, 1, 2 form one SCC via 0->1->2->1->0);
+        // 2 -> 3 is a tail edge into a singleton SCC.
+        let indptr = vec![0, 1, 3, 5, 5];
+        let indices = vec![1, 0, 2, 1, 3];
+        let graph = CsrGraph::new(4, indptr, indices).unwrap();
+
+        let comp = graph.strongly_connected_components();
+        assert_eq!(comp[0], comp[1]);
+        assert_eq!(comp[1], comp[2]);
+        assert_ne!(comp[2], comp[3]);
+    }
+
+    #[test]
+    fn test_strongly_connected_components_all_singletons_on_dag() {
+        let indptr = vec![0, 1, 2, 2];
+        let indices = vec![1, 2];
+        let graph = CsrGraph::new(3, indptr, indices).unwrap();
+
+        let comp = graph.strongly_connected_components();
+        let mut sorted = comp.clone();
+        sorted.sort_unstable();
+        sorted.dedup();
+        assert_eq!(sorted.len(), 3);
+    }
+
+    #[test]
+    fn test_condense_collapses_cycle_into_single_vertex() {
+        // 0 <-> 1, 1 <-> 2 (one SCC) -> 3 (singleton SCC).
+        let indptr = vec![0, 1, 3, 5, 5];
+        let indices = vec![1, 0, 2, 1, 3];
+        let graph = CsrGraph::new(4, indptr, indices).unwrap();
+
+        let (condensed, comp) = graph.condense();
+        assert_eq!(condensed.num_vertices(), 2);
+        assert_eq!(condensed.num_edges(), 1);
+        assert_eq!(comp[0], comp[1]);
+        assert_eq!(comp[1], comp[2]);
+        assert_ne!(comp[2], comp[3]);
+    }
+
+    #[test]
+    fn test_csr_graph_view_zero_copy_read() {
+        let indptr: Vec<i64> = vec![0, 2, 3, 4];
+        let indices: Vec<i64> = vec![1, 2, 0, 1];
+        let view = CsrGraphView::new(3, &indptr, &indices);
+
+        view.validate().unwrap();
+        assert_eq!(view.num_vertices(), 3);
+        assert_eq!(view.num_edges(), 4);
+        assert_eq!(view.neighbors(0).collect::<Vec<_>>(), vec![1, 2]);
+        assert_eq!(view.edge_range(1), (2, 3));
+    }
+
+    #[test]
+    fn test_csr_graph_view_rejects_out_of_range_index() {
+        let indptr: Vec<i64> = vec![0, 1, 1];
+        let indices: Vec<i64> = vec![5];
+        let view = CsrGraphView::new(2, &indptr, &indices);
+        assert!(view.validate().is_err());
+    }
+
+    #[test]
+    fn test_csr_graph_view_to_owned_matches_direct_construction() {
+        let indptr: Vec<i64> = vec![0, 1, 2, 2];
+        let indices: Vec<i64> = vec![1, 2];
+        let view = CsrGraphView::new(3, &indptr, &indices);
+
+        let owned = view.to_owned().unwrap();
+        let direct = CsrGraph::new(3, vec![0, 1, 2, 2], vec![1, 2]).unwrap();
+        assert_eq!(owned.indptr(), direct.indptr());
+        assert_eq!(owned.indices(), direct.indices());
+    }
 }
@@ -1,6 +1,7 @@
 use std::collections::BinaryHeap;
 use std::cmp::{Ordering, Reverse};
-use crate::csr::CsrGraph;
+use crate::block_heap::BucketHeap;
+use crate::csr::{CsrGraph, ShortestPathGraph};
 use crate::error::Result;
 use num_traits::Float;
 
@@ -95,7 +96,7 @@ where
             continue;
         }
 
-        let (start, end) = graph.edge_range(u);
+        let (start, _end) = graph.edge_range(u);
         for (eid, &v) in graph.neighbors(u).iter().enumerate() {
             let edge_idx = start + eid;
 
@@ -120,6 +121,502 @@ where
     Ok((dist, pred))
 }
 
+/// Dijkstra's algorithm with predecessor tracking, generic over any
+/// [`ShortestPathGraph`] rather than a concrete `&CsrGraph`
+///
+/// Identical to [`dijkstra_sssp_with_preds`] -- same heap, same stale-entry
+/// skip, same predecessor convention -- just written against the trait so
+/// it runs unchanged over a plain [`CsrGraph`] or over
+/// [`crate::csr::UndirectedCsrGraph`]'s doubled adjacency. The concrete
+/// entry points stay as they are rather than being rewritten against this
+/// trait, since they're already correct and tested; this is the place to
+/// reach for when a caller's graph isn't a bare `CsrGraph`.
+pub fn dijkstra_sssp_over_graph<G, T>(
+    graph: &G,
+    weights: &[T],
+    source: usize,
+    enabled: Option<&[bool]>,
+) -> Result<(Vec<T>, Vec<usize>)>
+where
+    G: ShortestPathGraph,
+    T: Float + Copy,
+{
+    let n = graph.num_vertices();
+    let mut dist = vec![T::infinity(); n];
+    let mut pred = vec![usize::MAX; n];
+    dist[source] = T::zero();
+    pred[source] = source;
+
+    let mut heap = BinaryHeap::new();
+    heap.push(Reverse((OrderedFloat(T::zero()), source)));
+
+    while let Some(Reverse((OrderedFloat(d), u))) = heap.pop() {
+        if d > dist[u] {
+            continue;
+        }
+
+        let (start, _end) = graph.edge_range(u);
+        for (eid, &v) in graph.neighbors(u).iter().enumerate() {
+            let edge_idx = start + eid;
+
+            if let Some(enabled_mask) = enabled {
+                if !enabled_mask[edge_idx] {
+                    continue;
+                }
+            }
+
+            let w = weights[edge_idx];
+            let new_dist = dist[u] + w;
+
+            if new_dist < dist[v] {
+                dist[v] = new_dist;
+                pred[v] = u;
+                heap.push(Reverse((OrderedFloat(new_dist), v)));
+            }
+        }
+    }
+
+    Ok((dist, pred))
+}
+
+/// A* single-target search driven by a user-supplied admissible heuristic
+///
+/// Orders the frontier by `g(v) + heuristic(v)` instead of the plain `g(v)`
+/// [`dijkstra_sssp_with_preds`] uses, reusing this module's own
+/// `OrderedFloat`/`BinaryHeap` rather than the block-frontier
+/// [`crate::bmssp::bmssp_astar`]. `heuristic` must never overestimate the
+/// true remaining distance to `target` for the result to be optimal.
+/// Search stops as soon as `target` is popped, so a good heuristic (e.g. a
+/// geometric or landmark lower bound) touches far fewer vertices than a
+/// full [`dijkstra_sssp`]. Returns the target's distance (`None` if
+/// unreachable) alongside the predecessor array built so far, which the
+/// caller can walk back with the same `usize::MAX`-sentinel convention
+/// every SSSP entry point in this crate uses.
+pub fn astar_sssp_with_heuristic<T, H>(
+    graph: &CsrGraph,
+    weights: &[T],
+    source: usize,
+    target: usize,
+    heuristic: H,
+    enabled: Option<&[bool]>,
+) -> Result<(Option<T>, Vec<usize>)>
+where
+    T: Float + Copy,
+    H: Fn(usize) -> T,
+{
+    let n = graph.num_vertices();
+    let mut dist = vec![T::infinity(); n];
+    let mut pred = vec![usize::MAX; n];
+    dist[source] = T::zero();
+    pred[source] = source;
+
+    let mut heap = BinaryHeap::new();
+    heap.push(Reverse((OrderedFloat(heuristic(source)), source)));
+
+    while let Some(Reverse((OrderedFloat(f), u))) = heap.pop() {
+        // Skip entries superseded by a later improvement of dist[u]; a
+        // vertex is expanded again whenever a cheaper path to it turns up.
+        if f > dist[u] + heuristic(u) {
+            continue;
+        }
+
+        if u == target {
+            return Ok((Some(dist[target]), pred));
+        }
+
+        let (start, _end) = graph.edge_range(u);
+        for (eid, &v) in graph.neighbors(u).iter().enumerate() {
+            let edge_idx = start + eid;
+
+            if let Some(enabled_mask) = enabled {
+                if !enabled_mask[edge_idx] {
+                    continue;
+                }
+            }
+
+            let w = weights[edge_idx];
+            let new_dist = dist[u] + w;
+
+            if new_dist < dist[v] {
+                dist[v] = new_dist;
+                pred[v] = u;
+                heap.push(Reverse((OrderedFloat(new_dist + heuristic(v)), v)));
+            }
+        }
+    }
+
+    Ok((None, pred))
+}
+
+/// Lazy Dijkstra search that yields `(vertex, distance)` pairs in
+/// nondecreasing distance order, one newly-settled vertex per [`next`](Iterator::next) call
+///
+/// Built via [`dijkstra_iter`]. Holds the same `BinaryHeap<Reverse<(OrderedFloat<T>, usize)>>`
+/// [`dijkstra_sssp_with_preds`] uses internally, just driven one pop at a
+/// time instead of to completion, so callers can stop early (e.g. "expand
+/// until distance exceeds B" or "stop at the first vertex in set S")
+/// without paying for the rest of the graph, and compose with the standard
+/// iterator combinators (`take_while`, `find`, ...) instead of hand-rolling
+/// a break condition.
+pub struct DijkstraIter<'a, T> {
+    graph: &'a CsrGraph,
+    weights: &'a [T],
+    enabled: Option<&'a [bool]>,
+    dist: Vec<T>,
+    heap: BinaryHeap<Reverse<(OrderedFloat<T>, usize)>>,
+}
+
+impl<'a, T> Iterator for DijkstraIter<'a, T>
+where
+    T: Float + Copy,
+{
+    type Item = (usize, T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let Reverse((OrderedFloat(d), u)) = self.heap.pop()?;
+
+            // Stale entry: a cheaper path to `u` was already settled.
+            if d > self.dist[u] {
+                continue;
+            }
+
+            let (start, _end) = self.graph.edge_range(u);
+            for (eid, &v) in self.graph.neighbors(u).iter().enumerate() {
+                let edge_idx = start + eid;
+
+                if let Some(enabled_mask) = self.enabled {
+                    if !enabled_mask[edge_idx] {
+                        continue;
+                    }
+                }
+
+                let w = self.weights[edge_idx];
+                let new_dist = d + w;
+
+                if new_dist < self.dist[v] {
+                    self.dist[v] = new_dist;
+                    self.heap.push(Reverse((OrderedFloat(new_dist), v)));
+                }
+            }
+
+            return Some((u, d));
+        }
+    }
+}
+
+/// Construct a [`DijkstraIter`] over `graph` rooted at `source`
+///
+/// Each vertex reachable from `source` is yielded exactly once, in
+/// nondecreasing distance order; unreachable vertices are never yielded.
+pub fn dijkstra_iter<'a, T>(
+    graph: &'a CsrGraph,
+    weights: &'a [T],
+    source: usize,
+    enabled: Option<&'a [bool]>,
+) -> DijkstraIter<'a, T>
+where
+    T: Float + Copy,
+{
+    let n = graph.num_vertices();
+    let mut dist = vec![T::infinity(); n];
+    dist[source] = T::zero();
+
+    let mut heap = BinaryHeap::new();
+    heap.push(Reverse((OrderedFloat(T::zero()), source)));
+
+    DijkstraIter { graph, weights, enabled, dist, heap }
+}
+
+/// Walk a predecessor array from `target` back to `source`, in travel order
+fn reconstruct_path(pred: &[usize], source: usize, target: usize) -> Option<Vec<usize>> {
+    if source != target && pred[target] == usize::MAX {
+        return None;
+    }
+    let mut path = vec![target];
+    let mut cur = target;
+    while cur != source {
+        cur = pred[cur];
+        if cur == usize::MAX {
+            return None;
+        }
+        path.push(cur);
+    }
+    path.reverse();
+    Some(path)
+}
+
+/// Index of an enabled edge `u -> v`, if one exists
+fn find_edge(graph: &CsrGraph, enabled: &[bool], u: usize, v: usize) -> Option<usize> {
+    let (start, _end) = graph.edge_range(u);
+    graph
+        .neighbors(u)
+        .iter()
+        .enumerate()
+        .find(|(eid, &w)| w == v && enabled[start + eid])
+        .map(|(eid, _)| start + eid)
+}
+
+/// Sum of edge weights along `path`, or `None` if some consecutive pair in
+/// `path` is not joined by an enabled edge
+fn path_cost<T>(graph: &CsrGraph, weights: &[T], enabled: &[bool], path: &[usize]) -> Option<T>
+where
+    T: Float + Copy,
+{
+    let mut total = T::zero();
+    for pair in path.windows(2) {
+        let (u, v) = (pair[0], pair[1]);
+        let edge_idx = find_edge(graph, enabled, u, v)?;
+        total = total + weights[edge_idx];
+    }
+    Some(total)
+}
+
+/// Yen's algorithm for the `k` shortest loopless paths from `source` to
+/// `target`, built directly on plain Dijkstra
+///
+/// Reuses the `enabled: Option<&[bool]>` edge mask every entry point in
+/// this crate already accepts as the edge-removal mechanism: the first
+/// path comes from [`dijkstra_sssp_with_preds`]. For each subsequent
+/// candidate, every "spur node" along the previous path temporarily
+/// disables (via a cloned mask) the edge that would repeat a shared root
+/// prefix of an already-found path, plus every other outgoing edge of the
+/// earlier root vertices, so a spur search can never revisit the root --
+/// guaranteeing loopless output rather than merely distinct paths. Dijkstra
+/// re-runs from the spur node to `target`, the root and spur splice into a
+/// candidate, and candidates are kept in a min-heap keyed by total cost;
+/// the cheapest not-yet-seen candidate becomes the next accepted path.
+///
+/// Returns fewer than `k` paths if the candidate heap empties first (i.e.
+/// there simply aren't `k` loopless source-target paths in the graph).
+pub fn k_shortest_paths<T>(
+    graph: &CsrGraph,
+    weights: &[T],
+    source: usize,
+    target: usize,
+    k: usize,
+    enabled: Option<&[bool]>,
+) -> Result<Vec<(T, Vec<usize>)>>
+where
+    T: Float + Copy,
+{
+    let mut mask: Vec<bool> = match enabled {
+        Some(mask) => mask.to_vec(),
+        None => vec![true; graph.num_edges()],
+    };
+
+    if k == 0 || source == target {
+        return Ok(Vec::new());
+    }
+
+    let (dist, pred) = dijkstra_sssp_with_preds(graph, weights, source, Some(&mask))?;
+    let Some(first_path) = reconstruct_path(&pred, source, target) else {
+        return Ok(Vec::new());
+    };
+
+    let mut found: Vec<(T, Vec<usize>)> = vec![(dist[target], first_path)];
+    let mut candidates: std::collections::BinaryHeap<Reverse<(OrderedFloat<T>, Vec<usize>)>> =
+        std::collections::BinaryHeap::new();
+
+    while found.len() < k {
+        let prev_path = found.last().unwrap().1.clone();
+
+        for i in 0..prev_path.len().saturating_sub(1) {
+            let spur_node = prev_path[i];
+            let root_path = &prev_path[..=i];
+
+            let mut disabled_edges = Vec::new();
+            for (_, path) in &found {
+                if path.len() > i + 1 && path[..=i] == *root_path {
+                    if let Some(edge_idx) = find_edge(graph, &mask, path[i], path[i + 1]) {
+                        mask[edge_idx] = false;
+                        disabled_edges.push(edge_idx);
+                    }
+                }
+            }
+            for &root_vertex in &root_path[..i] {
+                let (start, end) = graph.edge_range(root_vertex);
+                for edge_idx in start..end {
+                    if mask[edge_idx] {
+                        mask[edge_idx] = false;
+                        disabled_edges.push(edge_idx);
+                    }
+                }
+            }
+
+            let spur_result = dijkstra_sssp_with_preds(graph, weights, spur_node, Some(&mask));
+
+            for edge_idx in disabled_edges {
+                mask[edge_idx] = true;
+            }
+
+            let (_, spur_pred) = spur_result?;
+            if let Some(spur_path) = reconstruct_path(&spur_pred, spur_node, target) {
+                let mut total_path = root_path.to_vec();
+                total_path.pop();
+                total_path.extend(spur_path);
+
+                if let Some(cost) = path_cost(graph, weights, &mask, &total_path) {
+                    candidates.push(Reverse((OrderedFloat(cost), total_path)));
+                }
+            }
+        }
+
+        let mut accepted = false;
+        while let Some(Reverse((OrderedFloat(cost), path))) = candidates.pop() {
+            if !found.iter().any(|(_, p)| *p == path) {
+                found.push((cost, path));
+                accepted = true;
+                break;
+            }
+        }
+        if !accepted {
+            break;
+        }
+    }
+
+    Ok(found)
+}
+
+/// Dijkstra's algorithm using Dial's bucket queue, for graphs whose edge
+/// weights are integers (or pre-quantized to a fixed resolution) bounded by
+/// `max_weight`.
+///
+/// Replaces the comparison-based [`BinaryHeap`] with a [`BucketHeap`], so
+/// push and decrease-key are O(1) and the whole run costs O(m + C) instead
+/// of O(m log n) where `C = max_weight * max_degree`. Beats the
+/// float-weighted Dijkstra/BMSSP variants at road-network scale, where
+/// weights are naturally bounded integers.
+pub fn dijkstra_sssp_bucket_with_preds(
+    graph: &CsrGraph,
+    weights: &[usize],
+    source: usize,
+    enabled: Option<&[bool]>,
+    max_weight: usize,
+) -> Result<(Vec<usize>, Vec<usize>)> {
+    let n = graph.num_vertices();
+    let mut dist = vec![usize::MAX; n];
+    let mut pred = vec![usize::MAX; n];
+    dist[source] = 0;
+    pred[source] = source;
+
+    let max_degree = (0..n).map(|u| graph.neighbors(u).len()).max().unwrap_or(0).max(1);
+    let mut heap = BucketHeap::new(max_weight, max_degree);
+    heap.push(source, 0);
+
+    while !heap.is_empty() {
+        let (block, _b_next) = heap.pop_block(1);
+        let Some((u, d)) = block.into_iter().next() else {
+            break;
+        };
+
+        if d > dist[u] {
+            continue;
+        }
+
+        let (start, _end) = graph.edge_range(u);
+        for (eid, &v) in graph.neighbors(u).iter().enumerate() {
+            let edge_idx = start + eid;
+
+            if let Some(enabled_mask) = enabled {
+                if !enabled_mask[edge_idx] {
+                    continue;
+                }
+            }
+
+            let w = weights[edge_idx];
+            let new_dist = dist[u] + w;
+
+            if new_dist < dist[v] {
+                dist[v] = new_dist;
+                pred[v] = u;
+                heap.decrease_key(v, new_dist);
+            }
+        }
+    }
+
+    Ok((dist, pred))
+}
+
+/// Given final distances `dist` from `source`, build the shortest-path
+/// DAG: for each vertex, every predecessor `u` with `dist[u] + w(u, v)`
+/// within `epsilon` of `dist[v]` (every edge lying on some shortest path),
+/// plus `sigma[v]`, the count of distinct shortest paths to `v`
+/// (saturating to avoid overflow on dense graphs).
+///
+/// Processes vertices in non-decreasing `dist` order so each vertex's
+/// `sigma` is finalized before its successors consume it. Vertices at equal
+/// distance keep vertex-index order, so this assumes no zero-weight edge
+/// joins two vertices at the same distance.
+pub(crate) fn build_shortest_path_dag<T>(
+    graph: &CsrGraph,
+    weights: &[T],
+    enabled: Option<&[bool]>,
+    dist: &[T],
+    source: usize,
+    epsilon: f64,
+) -> (Vec<Vec<usize>>, Vec<u64>)
+where
+    T: Float + Copy,
+{
+    let n = graph.num_vertices();
+    let eps = T::from(epsilon).unwrap_or_else(T::zero);
+    let mut preds: Vec<Vec<usize>> = vec![Vec::new(); n];
+    let mut sigma = vec![0u64; n];
+    if dist[source].is_finite() {
+        sigma[source] = 1;
+    }
+
+    let mut order: Vec<usize> = (0..n).filter(|&v| dist[v].is_finite()).collect();
+    order.sort_by(|&a, &b| dist[a].partial_cmp(&dist[b]).unwrap());
+
+    for u in order {
+        let (start, _end) = graph.edge_range(u);
+        for (eid, &v) in graph.neighbors(u).iter().enumerate() {
+            let edge_idx = start + eid;
+            if let Some(mask) = enabled {
+                if !mask[edge_idx] {
+                    continue;
+                }
+            }
+            if !dist[v].is_finite() {
+                continue;
+            }
+
+            let candidate = dist[u] + weights[edge_idx];
+            if (candidate - dist[v]).abs() <= eps {
+                preds[v].push(u);
+                sigma[v] = sigma[v].saturating_add(sigma[u]);
+            }
+        }
+    }
+
+    (preds, sigma)
+}
+
+/// Dijkstra's algorithm that returns the full shortest-path DAG instead of
+/// a single predecessor per vertex
+///
+/// `preds[v]` lists every incoming edge lying on some shortest path to `v`
+/// (within `epsilon`, to tolerate floating-point tie noise), and
+/// `sigma[v]` is the number of distinct shortest paths to `v`. This is the
+/// representation needed to enumerate equal-cost path alternatives and to
+/// drive Brandes' betweenness centrality.
+pub fn dijkstra_sssp_dag<T>(
+    graph: &CsrGraph,
+    weights: &[T],
+    source: usize,
+    enabled: Option<&[bool]>,
+    epsilon: f64,
+) -> Result<(Vec<T>, Vec<Vec<usize>>, Vec<u64>)>
+where
+    T: Float + Copy,
+{
+    let dist = dijkstra_sssp(graph, weights, source, enabled)?;
+    let (preds, sigma) = build_shortest_path_dag(graph, weights, enabled, &dist, source, epsilon);
+    Ok((dist, preds, sigma))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -162,4 +659,245 @@ mod tests {
         assert_eq!(dist[1], 1.0);
         assert!(dist[2].is_infinite());
     }
+
+    #[test]
+    fn test_dijkstra_bucket_chain() {
+        // Graph: 0 -> 1 -> 2 (integer weights 1, 2)
+        let indptr = vec![0, 1, 2, 2];
+        let indices = vec![1, 2];
+        let graph = CsrGraph::new(3, indptr, indices).unwrap();
+        let weights = vec![1usize, 2usize];
+        let (dist, pred) = dijkstra_sssp_bucket_with_preds(&graph, &weights, 0, None, 2).unwrap();
+        assert_eq!(dist, vec![0, 1, 3]);
+        assert_eq!(pred[1], 0);
+        assert_eq!(pred[2], 1);
+    }
+
+    #[test]
+    fn test_dijkstra_bucket_matches_float_dijkstra() {
+        // Same diamond graph on both solvers, weights kept integral
+        let indptr = vec![0, 2, 3, 4, 4];
+        let indices = vec![1, 2, 3, 3];
+        let graph = CsrGraph::new(4, indptr, indices).unwrap();
+        let int_weights = vec![1usize, 5usize, 1usize, 1usize];
+        let float_weights = vec![1.0f32, 5.0f32, 1.0f32, 1.0f32];
+
+        let (int_dist, _) = dijkstra_sssp_bucket_with_preds(&graph, &int_weights, 0, None, 5).unwrap();
+        let float_dist = dijkstra_sssp(&graph, &float_weights, 0, None).unwrap();
+
+        for i in 0..4 {
+            assert_eq!(int_dist[i] as f32, float_dist[i]);
+        }
+    }
+
+    #[test]
+    fn test_dijkstra_bucket_disconnected() {
+        let indptr = vec![0, 1, 1, 1];
+        let indices = vec![1];
+        let graph = CsrGraph::new(3, indptr, indices).unwrap();
+        let weights = vec![1usize];
+        let (dist, _) = dijkstra_sssp_bucket_with_preds(&graph, &weights, 0, None, 1).unwrap();
+        assert_eq!(dist[0], 0);
+        assert_eq!(dist[1], 1);
+        assert_eq!(dist[2], usize::MAX);
+    }
+
+    #[test]
+    fn test_dijkstra_sssp_dag_tied_routes_both_counted() {
+        // Diamond with equal-cost routes 0->1->3 and 0->2->3 (cost 2 each).
+        let indptr = vec![0, 2, 3, 4, 4];
+        let indices = vec![1, 2, 3, 3];
+        let graph = CsrGraph::new(4, indptr, indices).unwrap();
+        let weights = vec![1.0f32, 1.0, 1.0, 1.0];
+
+        let (dist, preds, sigma) = dijkstra_sssp_dag(&graph, &weights, 0, None, 1e-6).unwrap();
+        assert_eq!(dist[3], 2.0);
+        assert_eq!(sigma[3], 2);
+        let mut via = preds[3].clone();
+        via.sort_unstable();
+        assert_eq!(via, vec![1, 2]);
+    }
+
+    #[test]
+    fn test_dijkstra_sssp_over_graph_matches_concrete_csr_graph() {
+        let indptr = vec![0, 1, 2, 2];
+        let indices = vec![1, 2];
+        let graph = CsrGraph::new(3, indptr, indices).unwrap();
+        let weights = vec![1.0f32, 2.0];
+
+        let expected = dijkstra_sssp_with_preds(&graph, &weights, 0, None).unwrap();
+        let actual = dijkstra_sssp_over_graph(&graph, &weights, 0, None).unwrap();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_dijkstra_sssp_over_graph_runs_over_undirected_adapter() {
+        use crate::csr::UndirectedCsrGraph;
+
+        // Directed chain 0 -> 1 -> 2; undirected, 2 should reach 0 too.
+        let indptr = vec![0, 1, 2, 2];
+        let indices = vec![1, 2];
+        let graph = CsrGraph::new(3, indptr, indices).unwrap();
+        let directed_weights = vec![1.0f32, 2.0];
+
+        let (undirected, edge_map) = UndirectedCsrGraph::from_directed(&graph);
+        let undirected_weights: Vec<f32> =
+            edge_map.iter().map(|&i| directed_weights[i]).collect();
+
+        let (dist, _) =
+            dijkstra_sssp_over_graph(&undirected, &undirected_weights, 2, None).unwrap();
+        assert_eq!(dist[2], 0.0);
+        assert_eq!(dist[1], 2.0);
+        assert_eq!(dist[0], 3.0);
+    }
+
+    #[test]
+    fn test_astar_sssp_with_heuristic_picks_shortest_of_two_routes() {
+        // 0 -> 1 -> 3 costs 1+5=6, 0 -> 2 -> 3 costs 2+1=3.
+        let indptr = vec![0, 2, 3, 4, 4];
+        let indices = vec![1, 2, 3, 3];
+        let graph = CsrGraph::new(4, indptr, indices).unwrap();
+        let weights = vec![1.0f32, 2.0, 5.0, 1.0];
+
+        let (dist, pred) =
+            astar_sssp_with_heuristic(&graph, &weights, 0, 3, |_| 0.0f32, None).unwrap();
+        assert_eq!(dist, Some(3.0));
+        assert_eq!(pred[3], 2);
+        assert_eq!(pred[2], 0);
+    }
+
+    #[test]
+    fn test_astar_sssp_with_heuristic_unreachable_target_returns_none() {
+        let indptr = vec![0, 1, 1, 1];
+        let indices = vec![1];
+        let graph = CsrGraph::new(3, indptr, indices).unwrap();
+        let weights = vec![1.0f32];
+
+        let (dist, _) =
+            astar_sssp_with_heuristic(&graph, &weights, 0, 2, |_| 0.0f32, None).unwrap();
+        assert!(dist.is_none());
+    }
+
+    #[test]
+    fn test_astar_sssp_with_heuristic_matches_dijkstra_distance() {
+        let indptr = vec![0, 1, 2, 2];
+        let indices = vec![1, 2];
+        let graph = CsrGraph::new(3, indptr, indices).unwrap();
+        let weights = vec![1.0f32, 2.0f32];
+
+        let plain = dijkstra_sssp(&graph, &weights, 0, None).unwrap();
+        let (dist, _) =
+            astar_sssp_with_heuristic(&graph, &weights, 0, 2, |_| 0.0f32, None).unwrap();
+        assert_eq!(dist, Some(plain[2]));
+    }
+
+    #[test]
+    fn test_k_shortest_paths_diamond_in_cost_order() {
+        // Diamond: 0 -> 1 -> 3 (cost 1+5=6), 0 -> 2 -> 3 (cost 2+1=3).
+        let indptr = vec![0, 2, 3, 4, 4];
+        let indices = vec![1, 2, 3, 3];
+        let graph = CsrGraph::new(4, indptr, indices).unwrap();
+        let weights = vec![1.0f32, 2.0, 5.0, 1.0];
+
+        let paths = k_shortest_paths(&graph, &weights, 0, 3, 2, None).unwrap();
+        assert_eq!(paths.len(), 2);
+        assert_eq!(paths[0], (3.0, vec![0, 2, 3]));
+        assert_eq!(paths[1], (6.0, vec![0, 1, 3]));
+    }
+
+    #[test]
+    fn test_k_shortest_paths_excludes_root_revisits() {
+        // 0 -> 1 -> 2 -> 3 is the only simple path; a 1 -> 0 back-edge
+        // exists but must never appear in a returned (loopless) path.
+        let indptr = vec![0, 1, 3, 4, 4];
+        let indices = vec![1, 2, 0, 3];
+        let graph = CsrGraph::new(4, indptr, indices).unwrap();
+        let weights = vec![1.0f32, 1.0, 1.0, 1.0];
+
+        let paths = k_shortest_paths(&graph, &weights, 0, 3, 5, None).unwrap();
+        assert!(!paths.is_empty());
+        for (_, path) in &paths {
+            let mut seen = std::collections::HashSet::new();
+            assert!(path.iter().all(|v| seen.insert(*v)), "path revisits a vertex: {:?}", path);
+        }
+    }
+
+    #[test]
+    fn test_k_shortest_paths_unreachable_target_returns_empty() {
+        let indptr = vec![0, 1, 1, 1];
+        let indices = vec![1];
+        let graph = CsrGraph::new(3, indptr, indices).unwrap();
+        let weights = vec![1.0f32];
+
+        let paths = k_shortest_paths(&graph, &weights, 0, 2, 3, None).unwrap();
+        assert!(paths.is_empty());
+    }
+
+    #[test]
+    fn test_dijkstra_iter_yields_vertices_in_nondecreasing_distance_order() {
+        // Diamond: 0 -> 1 -> 3 (cost 1+5=6), 0 -> 2 -> 3 (cost 2+1=3).
+        let indptr = vec![0, 2, 3, 4, 4];
+        let indices = vec![1, 2, 3, 3];
+        let graph = CsrGraph::new(4, indptr, indices).unwrap();
+        let weights = vec![1.0f32, 2.0, 5.0, 1.0];
+
+        let settled: Vec<(usize, f32)> = dijkstra_iter(&graph, &weights, 0, None).collect();
+        let order: Vec<usize> = settled.iter().map(|&(v, _)| v).collect();
+        assert_eq!(order, vec![0, 1, 2, 3]);
+        assert_eq!(settled[3], (3, 3.0));
+    }
+
+    #[test]
+    fn test_dijkstra_iter_skips_unreachable_vertices() {
+        let indptr = vec![0, 1, 1, 1];
+        let indices = vec![1];
+        let graph = CsrGraph::new(3, indptr, indices).unwrap();
+        let weights = vec![1.0f32];
+
+        let settled: Vec<(usize, f32)> = dijkstra_iter(&graph, &weights, 0, None).collect();
+        assert_eq!(settled, vec![(0, 0.0), (1, 1.0)]);
+    }
+
+    #[test]
+    fn test_dijkstra_iter_matches_dijkstra_sssp_as_a_collect() {
+        let indptr = vec![0, 1, 2, 2];
+        let indices = vec![1, 2];
+        let graph = CsrGraph::new(3, indptr, indices).unwrap();
+        let weights = vec![1.0f32, 2.0];
+
+        let mut dist = vec![f32::INFINITY; 3];
+        for (v, d) in dijkstra_iter(&graph, &weights, 0, None) {
+            dist[v] = d;
+        }
+
+        let expected = dijkstra_sssp(&graph, &weights, 0, None).unwrap();
+        assert_eq!(dist, expected);
+    }
+
+    #[test]
+    fn test_dijkstra_iter_supports_early_termination_via_take_while() {
+        // Chain 0 -> 1 -> 2 -> 3 with unit weights; stop once distance
+        // exceeds a small bound without ever visiting vertex 3.
+        let indptr = vec![0, 1, 2, 3, 3];
+        let indices = vec![1, 2, 3];
+        let graph = CsrGraph::new(4, indptr, indices).unwrap();
+        let weights = vec![1.0f32, 1.0, 1.0];
+
+        let visited: Vec<usize> = dijkstra_iter(&graph, &weights, 0, None)
+            .take_while(|&(_, d)| d <= 1.0)
+            .map(|(v, _)| v)
+            .collect();
+        assert_eq!(visited, vec![0, 1]);
+    }
+
+    #[test]
+    fn test_dijkstra_sssp_dag_single_path_has_sigma_one() {
+        let indptr = vec![0, 1, 2, 2];
+        let indices = vec![1, 2];
+        let graph = CsrGraph::new(3, indptr, indices).unwrap();
+        let weights = vec![1.0f32, 1.0];
+
+        let (_, _, sigma) = dijkstra_sssp_dag(&graph, &weights, 0, None, 1e-6).unwrap();
+        assert_eq!(sigma, vec![1, 1, 1]);
+    }
 }
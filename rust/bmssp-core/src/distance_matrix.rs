@@ -0,0 +1,106 @@
+use crate::bmssp::{bmssp_sssp_with_state, BmsspState};
+use crate::csr::CsrGraph;
+use crate::error::Result;
+use crate::matrix::Matrix;
+use num_traits::Float;
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// Many-to-many distance matrix, one row of distances per source
+///
+/// Runs one SSSP per entry of `sources`, packing the results into a
+/// row-major [`Matrix`] where row `i` holds the distances from
+/// `sources[i]`. When `targets` is given, each row is restricted to just
+/// those columns (in the order given) instead of materializing a full
+/// `sources.len() x graph.num_vertices()` table — useful when only a
+/// handful of destinations matter, as in TSP/VRP-style pairwise cost
+/// tables between a small set of points.
+///
+/// Under the `parallel` feature, sources are distributed across rayon
+/// threads via `map_init`, which lazily initializes one [`BmsspState`] per
+/// rayon job and reuses it for every source in that job (a thread can run
+/// several jobs, each with its own state). Without the feature, a single `BmsspState` is
+/// reused across sources sequentially via [`BmsspState::reset`].
+pub fn bmssp_distance_matrix<T>(
+    graph: &CsrGraph,
+    weights: &[T],
+    sources: &[usize],
+    targets: Option<&[usize]>,
+    enabled: Option<&[bool]>,
+) -> Result<Matrix<T>>
+where
+    T: Float + Copy + Send + Sync + 'static,
+{
+    let n = graph.num_vertices();
+    let stride = targets.map_or(n, |t| t.len());
+
+    let pack_row = |dist: &[T]| -> Vec<T> {
+        match targets {
+            Some(targets) => targets.iter().map(|&t| dist[t]).collect(),
+            None => dist.to_vec(),
+        }
+    };
+
+    #[cfg(feature = "parallel")]
+    let rows: Vec<Vec<T>> = sources
+        .par_iter()
+        .map_init(
+            || BmsspState::new(n),
+            |state, &source| {
+                bmssp_sssp_with_state(state, graph, weights, source, enabled)
+                    .map(|dist| pack_row(dist))
+            },
+        )
+        .collect::<Result<Vec<Vec<T>>>>()?;
+
+    #[cfg(not(feature = "parallel"))]
+    let rows: Vec<Vec<T>> = {
+        let mut state = BmsspState::new(n);
+        let mut rows = Vec::with_capacity(sources.len());
+        for &source in sources {
+            let dist = bmssp_sssp_with_state(&mut state, graph, weights, source, enabled)?;
+            rows.push(pack_row(dist));
+        }
+        rows
+    };
+
+    let mut data = Vec::with_capacity(rows.len() * stride);
+    for row in rows {
+        data.extend(row);
+    }
+
+    Ok(Matrix::new(data, stride))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_distance_matrix_full() {
+        // Chain: 0 -> 1 -> 2, weight 1.0 each
+        let indptr = vec![0, 1, 2, 2];
+        let indices = vec![1, 2];
+        let graph = CsrGraph::new(3, indptr, indices).unwrap();
+        let weights = vec![1.0f32, 1.0f32];
+
+        let m = bmssp_distance_matrix(&graph, &weights, &[0, 1], None, None).unwrap();
+        assert_eq!(m.rows(), 2);
+        assert_eq!(&m[0], &[0.0, 1.0, 2.0]);
+        assert_eq!(&m[1], &[f32::INFINITY, 0.0, 1.0]);
+    }
+
+    #[test]
+    fn test_distance_matrix_restricted_targets() {
+        let indptr = vec![0, 1, 2, 2];
+        let indices = vec![1, 2];
+        let graph = CsrGraph::new(3, indptr, indices).unwrap();
+        let weights = vec![1.0f32, 1.0f32];
+
+        let m = bmssp_distance_matrix(&graph, &weights, &[0, 1], Some(&[2, 0]), None).unwrap();
+        assert_eq!(m.rows(), 2);
+        assert_eq!(&m[0], &[2.0, 0.0]);
+        assert_eq!(&m[1], &[1.0, f32::INFINITY]);
+    }
+}
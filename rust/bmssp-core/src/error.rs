@@ -6,8 +6,10 @@ pub enum BmsspError {
     InvalidWeights(String),
     InvalidSource { source: usize, num_vertices: usize },
     InvalidEnabledMask { expected: usize, actual: usize },
+    InvalidSourceDist { expected: usize, actual: usize },
     NonFiniteWeight,
     NegativeWeight,
+    ParseError(String),
 }
 
 impl fmt::Display for BmsspError {
@@ -29,8 +31,16 @@ impl fmt::Display for BmsspError {
                     expected, actual
                 )
             }
+            BmsspError::InvalidSourceDist { expected, actual } => {
+                write!(
+                    f,
+                    "Invalid source distances length: expected {} (one per source), got {}",
+                    expected, actual
+                )
+            }
             BmsspError::NonFiniteWeight => write!(f, "Non-finite weight encountered"),
             BmsspError::NegativeWeight => write!(f, "Negative weight encountered"),
+            BmsspError::ParseError(msg) => write!(f, "Parse error: {}", msg),
         }
     }
 }
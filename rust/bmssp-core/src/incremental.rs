@@ -0,0 +1,250 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+use num_traits::Float;
+
+use crate::csr::CsrGraph;
+use crate::dijkstra::dijkstra_sssp_with_preds;
+use crate::error::Result;
+use crate::ordered_float::OrderedFloat;
+use crate::validation;
+
+/// The source vertex whose outgoing edge starts at CSR edge index `eid`,
+/// found by binary search over `indptr` (which is non-decreasing).
+fn edge_source(graph: &CsrGraph, eid: usize) -> usize {
+    graph.indptr().partition_point(|&p| p <= eid) - 1
+}
+
+/// Stateful single-source shortest paths that supports toggling individual
+/// edges on/off with localized repair instead of a from-scratch rerun
+///
+/// Built for the common pattern of flipping edges (simulating failures or
+/// capacity changes) and re-querying distances repeatedly on a large
+/// graph, where a full [`dijkstra_sssp`](crate::dijkstra::dijkstra_sssp)
+/// per toggle would redo work far from the changed edge every time.
+pub struct SsspState<T> {
+    source: usize,
+    dist: Vec<T>,
+    pred: Vec<usize>,
+    enabled: Vec<bool>,
+}
+
+impl<T> SsspState<T>
+where
+    T: Float + Copy,
+{
+    /// Run an initial full SSSP from `source`, keeping the resulting
+    /// distances/predecessors/edge mask around for incremental repair
+    pub fn new(
+        graph: &CsrGraph,
+        weights: &[T],
+        source: usize,
+        enabled: Option<&[bool]>,
+    ) -> Result<Self> {
+        validation::validate_source(graph, source)?;
+        validation::validate_weights_len(graph, weights.len())?;
+        let mask = match enabled {
+            Some(mask) => {
+                validation::validate_enabled_mask(graph.num_edges(), mask)?;
+                mask.to_vec()
+            }
+            None => vec![true; graph.num_edges()],
+        };
+
+        let (dist, pred) = dijkstra_sssp_with_preds(graph, weights, source, Some(&mask))?;
+        Ok(Self {
+            source,
+            dist,
+            pred,
+            enabled: mask,
+        })
+    }
+
+    /// Current distance from the source to each vertex
+    pub fn distances(&self) -> &[T] {
+        &self.dist
+    }
+
+    /// Current predecessor of each vertex on its shortest path
+    pub fn predecessors(&self) -> &[usize] {
+        &self.pred
+    }
+
+    /// Run a bounded relaxation from every vertex currently in `heap`,
+    /// stopping naturally once no frontier entry can improve anything —
+    /// the same stale-entry skip every Dijkstra variant in this crate uses
+    fn relax_from(
+        &mut self,
+        graph: &CsrGraph,
+        weights: &[T],
+        mut heap: BinaryHeap<Reverse<(OrderedFloat<T>, usize)>>,
+    ) {
+        while let Some(Reverse((OrderedFloat(d), u))) = heap.pop() {
+            if d > self.dist[u] {
+                continue;
+            }
+
+            let (start, _end) = graph.edge_range(u);
+            for (eid, &v) in graph.neighbors(u).iter().enumerate() {
+                let edge_idx = start + eid;
+                if !self.enabled[edge_idx] {
+                    continue;
+                }
+
+                let candidate = d + weights[edge_idx];
+                if candidate < self.dist[v] {
+                    self.dist[v] = candidate;
+                    self.pred[v] = u;
+                    heap.push(Reverse((OrderedFloat(candidate), v)));
+                }
+            }
+        }
+    }
+
+    /// Enable the edge at CSR index `eid`, relaxing only the vertices it
+    /// can actually improve
+    ///
+    /// If `dist[u] + w < dist[v]` for the edge's endpoints, `v`'s distance
+    /// improves and a bounded Dijkstra reseeded from `v` propagates the
+    /// improvement onward; otherwise nothing in the tree changes.
+    pub fn enable_edge(&mut self, graph: &CsrGraph, weights: &[T], eid: usize) {
+        if self.enabled[eid] {
+            return;
+        }
+        self.enabled[eid] = true;
+
+        let u = edge_source(graph, eid);
+        let v = graph.indices()[eid];
+        if !self.dist[u].is_finite() {
+            return;
+        }
+
+        let candidate = self.dist[u] + weights[eid];
+        if candidate < self.dist[v] {
+            self.dist[v] = candidate;
+            self.pred[v] = u;
+            let mut heap = BinaryHeap::new();
+            heap.push(Reverse((OrderedFloat(candidate), v)));
+            self.relax_from(graph, weights, heap);
+        }
+    }
+
+    /// Disable the edge at CSR index `eid`, repairing only the part of the
+    /// shortest-path tree that actually depended on it
+    ///
+    /// If the edge wasn't on the tree (`pred[v] != u`), nothing changes.
+    /// Otherwise: find every vertex whose current path routes through `v`
+    /// (its subtree under `pred`), invalidate their distances, then re-seed
+    /// a bounded Dijkstra from every still-valid vertex that has an enabled
+    /// edge into the invalidated region — the classic dynamic-Dijkstra
+    /// repair, localized to the affected region instead of the whole graph.
+    pub fn disable_edge(&mut self, graph: &CsrGraph, weights: &[T], eid: usize) {
+        if !self.enabled[eid] {
+            return;
+        }
+        self.enabled[eid] = false;
+
+        let u = edge_source(graph, eid);
+        let v = graph.indices()[eid];
+        if self.pred[v] != u {
+            return;
+        }
+
+        let n = self.dist.len();
+        let mut affected = vec![false; n];
+        affected[v] = true;
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for x in 0..n {
+                if !affected[x] && x != self.source && self.pred[x] != usize::MAX && affected[self.pred[x]] {
+                    affected[x] = true;
+                    changed = true;
+                }
+            }
+        }
+
+        for ((d, p), &aff) in self.dist.iter_mut().zip(self.pred.iter_mut()).zip(affected.iter()) {
+            if aff {
+                *d = T::infinity();
+                *p = usize::MAX;
+            }
+        }
+
+        let mut heap = BinaryHeap::new();
+        for (boundary, &aff) in affected.iter().enumerate() {
+            if aff || !self.dist[boundary].is_finite() {
+                continue;
+            }
+            let (start, end) = graph.edge_range(boundary);
+            let reaches_affected = (start..end)
+                .any(|edge_idx| self.enabled[edge_idx] && affected[graph.indices()[edge_idx]]);
+            if reaches_affected {
+                heap.push(Reverse((OrderedFloat(self.dist[boundary]), boundary)));
+            }
+        }
+
+        self.relax_from(graph, weights, heap);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sssp_state_disable_edge_reroutes_through_alternate() {
+        // Diamond: 0->1->3 (cost 1+5=6), 0->2->3 (cost 2+1=3). Disabling
+        // 0->2 forces the tree back onto the heavier route.
+        let indptr = vec![0, 2, 3, 4, 4];
+        let indices = vec![1, 2, 3, 3];
+        let graph = CsrGraph::new(4, indptr, indices).unwrap();
+        let weights = vec![1.0f32, 2.0, 5.0, 1.0];
+
+        let mut state = SsspState::new(&graph, &weights, 0, None).unwrap();
+        assert_eq!(state.distances()[3], 3.0);
+
+        state.disable_edge(&graph, &weights, 1); // the 0 -> 2 edge
+        assert_eq!(state.distances()[3], 6.0);
+        assert_eq!(state.distances()[2], f32::INFINITY);
+    }
+
+    #[test]
+    fn test_sssp_state_enable_edge_reestablishes_shortcut() {
+        let indptr = vec![0, 2, 3, 4, 4];
+        let indices = vec![1, 2, 3, 3];
+        let graph = CsrGraph::new(4, indptr, indices).unwrap();
+        let weights = vec![1.0f32, 2.0, 5.0, 1.0];
+
+        let disabled = vec![true, false, true, true];
+        let mut state = SsspState::new(&graph, &weights, 0, Some(&disabled)).unwrap();
+        assert_eq!(state.distances()[3], 6.0);
+
+        state.enable_edge(&graph, &weights, 1);
+        assert_eq!(state.distances()[3], 3.0);
+    }
+
+    #[test]
+    fn test_sssp_state_matches_fresh_dijkstra_after_toggle_sequence() {
+        let indptr = vec![0, 2, 3, 4, 4];
+        let indices = vec![1, 2, 3, 3];
+        let graph = CsrGraph::new(4, indptr, indices).unwrap();
+        let weights = vec![1.0f32, 2.0, 5.0, 1.0];
+
+        let mut state = SsspState::new(&graph, &weights, 0, None).unwrap();
+        let toggles: &[(usize, bool)] = &[(1, false), (3, false), (1, true), (3, true), (0, false)];
+
+        let mut mask = vec![true; 4];
+        for &(eid, enable) in toggles {
+            if enable {
+                state.enable_edge(&graph, &weights, eid);
+            } else {
+                state.disable_edge(&graph, &weights, eid);
+            }
+            mask[eid] = enable;
+        }
+
+        let (fresh_dist, _) = dijkstra_sssp_with_preds(&graph, &weights, 0, Some(&mask)).unwrap();
+        assert_eq!(state.distances(), fresh_dist.as_slice());
+    }
+}
@@ -0,0 +1,326 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+use num_traits::Float;
+
+use crate::bmssp::bmssp_sssp_with_preds;
+use crate::csr::CsrGraph;
+use crate::error::Result;
+use crate::ordered_float::OrderedFloat;
+
+/// Walk a predecessor array from `target` back to `source`, returning the
+/// vertex sequence in travel order (`source` first, `target` last).
+///
+/// Returns `None` if `target` is unreachable from `source` (detected via
+/// the `usize::MAX` sentinel that every BMSSP/Dijkstra entry point in this
+/// crate uses for "no predecessor").
+pub fn reconstruct_path(pred: &[usize], source: usize, target: usize) -> Option<Vec<usize>> {
+    if source != target && pred[target] == usize::MAX {
+        return None;
+    }
+    let mut path = vec![target];
+    let mut cur = target;
+    while cur != source {
+        cur = pred[cur];
+        if cur == usize::MAX {
+            return None;
+        }
+        path.push(cur);
+    }
+    path.reverse();
+    Some(path)
+}
+
+/// Sum of edge weights along `path`, or `None` if some consecutive pair in
+/// `path` is not joined by an enabled edge.
+fn path_cost<T>(graph: &CsrGraph, weights: &[T], enabled: &[bool], path: &[usize]) -> Option<T>
+where
+    T: Float + Copy,
+{
+    let mut total = T::zero();
+    for pair in path.windows(2) {
+        let (u, v) = (pair[0], pair[1]);
+        let edge_idx = find_edge(graph, enabled, u, v)?;
+        total = total + weights[edge_idx];
+    }
+    Some(total)
+}
+
+/// Index of an enabled edge `u -> v`, if one exists
+fn find_edge(graph: &CsrGraph, enabled: &[bool], u: usize, v: usize) -> Option<usize> {
+    let (start, _end) = graph.edge_range(u);
+    graph
+        .neighbors(u)
+        .iter()
+        .enumerate()
+        .find(|(eid, &w)| w == v && enabled[start + eid])
+        .map(|(eid, _)| start + eid)
+}
+
+/// Yen-style search for the `k` shortest distinct paths from `source` to
+/// `target`
+///
+/// Builds on the `enabled: Option<&[bool]>` edge mask that every BMSSP
+/// entry point already accepts: the shortest path (A₁) comes straight from
+/// [`bmssp_sssp_with_preds`]. Each subsequent candidate is found by, for
+/// every "spur node" along the previous path, temporarily disabling the
+/// edges that would repeat a shared prefix of an already-found path, then
+/// re-running SSSP from the spur node and splicing its root prefix with
+/// the spur's shortest suffix to `target`. Candidates are kept in a min-heap
+/// keyed by total cost; the cheapest not-yet-seen candidate is accepted
+/// into the result on each round.
+///
+/// Only the shared-prefix edges are disabled, so a spur path can pass back
+/// through a root-prefix vertex and a returned path is not guaranteed to be
+/// loopless; [`yen_ksp`] also disables the root-prefix vertices.
+///
+/// Returns fewer than `k` paths if the candidate heap empties first (i.e.
+/// no further distinct source-target path turns up).
+pub fn bmssp_k_shortest_paths<T>(
+    graph: &CsrGraph,
+    weights: &[T],
+    source: usize,
+    target: usize,
+    k: usize,
+    enabled: Option<&[bool]>,
+) -> Result<Vec<(T, Vec<usize>)>>
+where
+    T: Float + Copy + Send + Sync + 'static,
+{
+    let mut mask: Vec<bool> = match enabled {
+        Some(mask) => mask.to_vec(),
+        None => vec![true; graph.num_edges()],
+    };
+
+    if k == 0 || source == target {
+        return Ok(Vec::new());
+    }
+
+    let (dist, pred) = bmssp_sssp_with_preds(graph, weights, source, Some(&mask))?;
+    let Some(first_path) = reconstruct_path(&pred, source, target) else {
+        return Ok(Vec::new());
+    };
+
+    let mut found: Vec<(T, Vec<usize>)> = vec![(dist[target], first_path)];
+    let mut candidates: BinaryHeap<Reverse<(OrderedFloat<T>, Vec<usize>)>> = BinaryHeap::new();
+
+    while found.len() < k {
+        let prev_path = found.last().unwrap().1.clone();
+
+        for i in 0..prev_path.len().saturating_sub(1) {
+            let spur_node = prev_path[i];
+            let root_path = &prev_path[..=i];
+
+            let mut disabled_edges = Vec::new();
+            for (_, path) in &found {
+                if path.len() > i + 1 && path[..=i] == *root_path {
+                    if let Some(edge_idx) = find_edge(graph, &mask, path[i], path[i + 1]) {
+                        mask[edge_idx] = false;
+                        disabled_edges.push(edge_idx);
+                    }
+                }
+            }
+
+            let spur_result = bmssp_sssp_with_preds(graph, weights, spur_node, Some(&mask));
+
+            for edge_idx in disabled_edges {
+                mask[edge_idx] = true;
+            }
+
+            let (_, spur_pred) = spur_result?;
+            if let Some(spur_path) = reconstruct_path(&spur_pred, spur_node, target) {
+                let mut total_path = root_path.to_vec();
+                total_path.pop();
+                total_path.extend(spur_path);
+
+                if let Some(cost) = path_cost(graph, weights, &mask, &total_path) {
+                    candidates.push(Reverse((OrderedFloat(cost), total_path)));
+                }
+            }
+        }
+
+        let mut accepted = false;
+        while let Some(Reverse((OrderedFloat(cost), path))) = candidates.pop() {
+            if !found.iter().any(|(_, p)| *p == path) {
+                found.push((cost, path));
+                accepted = true;
+                break;
+            }
+        }
+        if !accepted {
+            break;
+        }
+    }
+
+    Ok(found)
+}
+
+/// Yen's `k` shortest loopless paths, strict variant
+///
+/// [`bmssp_k_shortest_paths`] only disables the single edge that would
+/// repeat a shared path prefix, which is enough to avoid returning a
+/// duplicate path but can still let a spur route back through an earlier
+/// root vertex via a different edge. `yen_ksp` additionally disables every
+/// outgoing edge of each root-prefix vertex (other than the spur node
+/// itself) while searching for the spur path, so every returned path is
+/// guaranteed loopless rather than merely distinct.
+pub fn yen_ksp<T>(
+    graph: &CsrGraph,
+    weights: &[T],
+    source: usize,
+    target: usize,
+    k: usize,
+    enabled: Option<&[bool]>,
+) -> Result<Vec<(T, Vec<usize>)>>
+where
+    T: Float + Copy + Send + Sync + 'static,
+{
+    let mut mask: Vec<bool> = match enabled {
+        Some(mask) => mask.to_vec(),
+        None => vec![true; graph.num_edges()],
+    };
+
+    if k == 0 || source == target {
+        return Ok(Vec::new());
+    }
+
+    let (dist, pred) = bmssp_sssp_with_preds(graph, weights, source, Some(&mask))?;
+    let Some(first_path) = reconstruct_path(&pred, source, target) else {
+        return Ok(Vec::new());
+    };
+
+    let mut found: Vec<(T, Vec<usize>)> = vec![(dist[target], first_path)];
+    let mut candidates: BinaryHeap<Reverse<(OrderedFloat<T>, Vec<usize>)>> = BinaryHeap::new();
+
+    while found.len() < k {
+        let prev_path = found.last().unwrap().1.clone();
+
+        for i in 0..prev_path.len().saturating_sub(1) {
+            let spur_node = prev_path[i];
+            let root_path = &prev_path[..=i];
+
+            let mut disabled_edges = Vec::new();
+            for (_, path) in &found {
+                if path.len() > i + 1 && path[..=i] == *root_path {
+                    if let Some(edge_idx) = find_edge(graph, &mask, path[i], path[i + 1]) {
+                        mask[edge_idx] = false;
+                        disabled_edges.push(edge_idx);
+                    }
+                }
+            }
+            for &root_vertex in &root_path[..i] {
+                let (start, end) = graph.edge_range(root_vertex);
+                for edge_idx in start..end {
+                    if mask[edge_idx] {
+                        mask[edge_idx] = false;
+                        disabled_edges.push(edge_idx);
+                    }
+                }
+            }
+
+            let spur_result = bmssp_sssp_with_preds(graph, weights, spur_node, Some(&mask));
+
+            for edge_idx in disabled_edges {
+                mask[edge_idx] = true;
+            }
+
+            let (_, spur_pred) = spur_result?;
+            if let Some(spur_path) = reconstruct_path(&spur_pred, spur_node, target) {
+                let mut total_path = root_path.to_vec();
+                total_path.pop();
+                total_path.extend(spur_path);
+
+                if let Some(cost) = path_cost(graph, weights, &mask, &total_path) {
+                    candidates.push(Reverse((OrderedFloat(cost), total_path)));
+                }
+            }
+        }
+
+        let mut accepted = false;
+        while let Some(Reverse((OrderedFloat(cost), path))) = candidates.pop() {
+            if !found.iter().any(|(_, p)| *p == path) {
+                found.push((cost, path));
+                accepted = true;
+                break;
+            }
+        }
+        if !accepted {
+            break;
+        }
+    }
+
+    Ok(found)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::csr::CsrGraph;
+
+    #[test]
+    fn test_k_shortest_single_path_graph() {
+        // Chain: 0 -> 1 -> 2, only one loopless path exists.
+        let indptr = vec![0, 1, 2, 2];
+        let indices = vec![1, 2];
+        let graph = CsrGraph::new(3, indptr, indices).unwrap();
+        let weights = vec![1.0f32, 1.0f32];
+
+        let paths = bmssp_k_shortest_paths(&graph, &weights, 0, 2, 3, None).unwrap();
+        assert_eq!(paths.len(), 1);
+        assert_eq!(paths[0], (2.0, vec![0, 1, 2]));
+    }
+
+    #[test]
+    fn test_k_shortest_diamond_returns_both_routes_in_order() {
+        // Diamond: 0 -> 1 -> 3 (cost 1+5=6), 0 -> 2 -> 3 (cost 2+1=3).
+        let indptr = vec![0, 2, 3, 4, 4];
+        let indices = vec![1, 2, 3, 3];
+        let graph = CsrGraph::new(4, indptr, indices).unwrap();
+        let weights = vec![1.0f32, 2.0, 5.0, 1.0];
+
+        let paths = bmssp_k_shortest_paths(&graph, &weights, 0, 3, 2, None).unwrap();
+        assert_eq!(paths.len(), 2);
+        assert_eq!(paths[0], (3.0, vec![0, 2, 3]));
+        assert_eq!(paths[1], (6.0, vec![0, 1, 3]));
+    }
+
+    #[test]
+    fn test_k_shortest_unreachable_target_returns_empty() {
+        let indptr = vec![0, 1, 1, 1];
+        let indices = vec![1];
+        let graph = CsrGraph::new(3, indptr, indices).unwrap();
+        let weights = vec![1.0f32];
+
+        let paths = bmssp_k_shortest_paths(&graph, &weights, 0, 2, 3, None).unwrap();
+        assert!(paths.is_empty());
+    }
+
+    #[test]
+    fn test_yen_ksp_diamond_matches_edge_only_variant() {
+        let indptr = vec![0, 2, 3, 4, 4];
+        let indices = vec![1, 2, 3, 3];
+        let graph = CsrGraph::new(4, indptr, indices).unwrap();
+        let weights = vec![1.0f32, 2.0, 5.0, 1.0];
+
+        let paths = yen_ksp(&graph, &weights, 0, 3, 2, None).unwrap();
+        assert_eq!(paths.len(), 2);
+        assert_eq!(paths[0], (3.0, vec![0, 2, 3]));
+        assert_eq!(paths[1], (6.0, vec![0, 1, 3]));
+    }
+
+    #[test]
+    fn test_yen_ksp_excludes_root_revisits() {
+        // 0 -> 1 -> 2 -> 3 is the only simple path; a 1 -> 0 back-edge
+        // exists but must never appear in a returned (loopless) path.
+        let indptr = vec![0, 1, 3, 4, 4];
+        let indices = vec![1, 2, 0, 3];
+        let graph = CsrGraph::new(4, indptr, indices).unwrap();
+        let weights = vec![1.0f32, 1.0, 1.0, 1.0];
+
+        let paths = yen_ksp(&graph, &weights, 0, 3, 5, None).unwrap();
+        for (_, path) in &paths {
+            let mut seen = std::collections::HashSet::new();
+            assert!(path.iter().all(|v| seen.insert(*v)), "path revisits a vertex: {:?}", path);
+        }
+    }
+}
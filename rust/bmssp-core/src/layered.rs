@@ -0,0 +1,145 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+use num_traits::Float;
+
+use crate::csr::CsrGraph;
+use crate::error::Result;
+use crate::ordered_float::OrderedFloat;
+
+/// Dimension-expanded (layered) shortest paths over a product state space
+/// `(vertex, layer)` with `layer in 0..num_layers`
+///
+/// `transition(u, layer, edge_idx)` is consulted for every real CSR edge
+/// leaving `u` while expanding state `(u, layer)`: returning
+/// `Some((next_layer, extra_cost))` allows the move, landing on
+/// `(v, next_layer)` at `dist[u][layer] + weights[edge_idx] + extra_cost`;
+/// returning `None` forbids the edge in that layer. This supports
+/// constraints like "at most K toll edges used", hop parity, or
+/// fuel/charge level without the caller materializing an expanded CSR
+/// graph with `n * num_layers` vertices -- the expansion only ever exists
+/// as the `(vertex, layer)` pairs touched by the search.
+///
+/// Internally this is the same binary-heap Dijkstra as
+/// [`crate::dijkstra_sssp`], just keyed by the pair instead of by vertex
+/// alone. `dist[source][0]` starts at zero; every other state starts at
+/// infinity. Returns a `Vec<Vec<T>>` of shape `[n][num_layers]`.
+pub fn layered_sssp<T, F>(
+    graph: &CsrGraph,
+    weights: &[T],
+    source: usize,
+    num_layers: usize,
+    transition: F,
+) -> Result<Vec<Vec<T>>>
+where
+    T: Float + Copy,
+    F: Fn(usize, usize, usize) -> Option<(usize, T)>,
+{
+    let n = graph.num_vertices();
+    let mut dist = vec![vec![T::infinity(); num_layers]; n];
+
+    if num_layers == 0 {
+        return Ok(dist);
+    }
+
+    dist[source][0] = T::zero();
+
+    let mut heap = BinaryHeap::new();
+    heap.push(Reverse((OrderedFloat(T::zero()), source, 0usize)));
+
+    while let Some(Reverse((OrderedFloat(d), u, layer))) = heap.pop() {
+        if d > dist[u][layer] {
+            continue;
+        }
+
+        let (start, _end) = graph.edge_range(u);
+        for (eid, &v) in graph.neighbors(u).iter().enumerate() {
+            let edge_idx = start + eid;
+
+            let Some((next_layer, extra_cost)) = transition(u, layer, edge_idx) else {
+                continue;
+            };
+            if next_layer >= num_layers {
+                continue;
+            }
+
+            let w = weights[edge_idx];
+            let new_dist = d + w + extra_cost;
+
+            if new_dist < dist[v][next_layer] {
+                dist[v][next_layer] = new_dist;
+                heap.push(Reverse((OrderedFloat(new_dist), v, next_layer)));
+            }
+        }
+    }
+
+    Ok(dist)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_layered_sssp_matches_plain_dijkstra_with_single_layer() {
+        // Chain 0 -> 1 -> 2, weights 1.0, 2.0; a single layer degenerates
+        // to plain Dijkstra.
+        let indptr = vec![0, 1, 2, 2];
+        let indices = vec![1, 2];
+        let graph = CsrGraph::new(3, indptr, indices).unwrap();
+        let weights = vec![1.0f32, 2.0];
+
+        let dist = layered_sssp(&graph, &weights, 0, 1, |_u, layer, _edge_idx| {
+            Some((layer, 0.0))
+        })
+        .unwrap();
+
+        assert_eq!(dist[0][0], 0.0);
+        assert_eq!(dist[1][0], 1.0);
+        assert_eq!(dist[2][0], 3.0);
+    }
+
+    #[test]
+    fn test_layered_sssp_bounds_toll_edge_usage() {
+        // Diamond: 0 -> 1 -> 3 (cheap but "toll"), 0 -> 2 -> 3 (expensive, no toll).
+        // Edge 0 (0->1) is the toll edge: using it bumps the layer by one.
+        // With num_layers = 1 (budget of zero tolls) the toll edge is forbidden.
+        let indptr = vec![0, 2, 3, 4, 4];
+        let indices = vec![1, 2, 3, 3];
+        let graph = CsrGraph::new(4, indptr, indices).unwrap();
+        let weights = vec![1.0f32, 10.0, 1.0, 1.0];
+        let toll_edge = 0usize;
+
+        let transition = |_u: usize, layer: usize, edge_idx: usize| {
+            if edge_idx == toll_edge {
+                let next = layer + 1;
+                if next >= 2 {
+                    return None;
+                }
+                Some((next, 0.0))
+            } else {
+                Some((layer, 0.0))
+            }
+        };
+
+        let budget_zero = layered_sssp(&graph, &weights, 0, 1, transition).unwrap();
+        // Layer 0 can't use the toll edge at all, so only 0->2->3 is available.
+        assert_eq!(budget_zero[3][0], 11.0);
+
+        let budget_one = layered_sssp(&graph, &weights, 0, 2, transition).unwrap();
+        // With one toll allowed, 0->1->3 (cost 1+1=2) becomes reachable at layer 1.
+        assert_eq!(budget_one[3][1], 2.0);
+    }
+
+    #[test]
+    fn test_layered_sssp_zero_layers_returns_all_infinite() {
+        let indptr = vec![0, 1, 1];
+        let indices = vec![1];
+        let graph = CsrGraph::new(2, indptr, indices).unwrap();
+        let weights = vec![1.0f32];
+
+        let dist = layered_sssp(&graph, &weights, 0, 0, |_, layer, _| Some((layer, 0.0))).unwrap();
+        assert_eq!(dist.len(), 2);
+        assert!(dist[0].is_empty());
+    }
+}
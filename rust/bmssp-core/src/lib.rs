@@ -1,16 +1,49 @@
+pub mod batch;
+pub mod bitset;
 pub mod csr;
 pub mod dijkstra;
 pub mod error;
 pub mod validation;
 pub mod bmssp;
+pub mod bidirectional;
 pub mod params;
 pub mod block_heap;
 pub mod pivot;
 pub mod ordered_float;
+pub mod quantile;
+pub mod matrix;
+pub mod distance_matrix;
+pub mod k_shortest;
+pub mod block_queue;
+pub mod recursive;
+pub mod paths;
+pub mod loaders;
+pub mod semiring;
+pub mod alt;
+pub mod centrality;
+pub mod incremental;
+pub mod layered;
 
-pub use csr::CsrGraph;
-pub use dijkstra::{dijkstra_sssp, dijkstra_sssp_with_preds};
-pub use bmssp::{bmssp_sssp, bmssp_sssp_with_preds, bmssp_sssp_with_state, bmssp_sssp_with_preds_and_state, BmsspState};
+pub use batch::bmssp_sssp_batch;
+pub use bitset::EnabledMask;
+pub use csr::{CsrGraph, CsrGraphView, ShortestPathGraph, UndirectedCsrGraph};
+pub use dijkstra::{dijkstra_sssp, dijkstra_sssp_with_preds, dijkstra_sssp_bucket_with_preds, dijkstra_sssp_dag, k_shortest_paths, astar_sssp_with_heuristic, dijkstra_iter, DijkstraIter, dijkstra_sssp_over_graph};
+pub use bmssp::{bmssp_sssp, bmssp_sssp_with_preds, bmssp_sssp_multi, bmssp_sssp_with_preds_multi, bmssp_multi_source, bmssp_multi_source_within_bound, bmssp_astar, bmssp_sssp_with_preds_tolerant, bmssp_sssp_with_state, bmssp_sssp_with_preds_and_state, bmssp_sssp_with_preds_and_state_masked, bmssp_sssp_dag, bmssp_sssp_to_target, bmssp_sssp_to_target_with_state, bmssp_sssp_with_frontier, BmsspState};
+pub use bidirectional::{bidirectional_sssp, bmssp_bidirectional};
+pub use quantile::GkSummary;
 pub use error::{BmsspError, Result};
 pub use params::BmsspParams;
-pub use block_heap::{BlockHeap, FastBlockHeap};
+pub use block_heap::{BlockHeap, FastBlockHeap, BucketHeap, IndexedBlockHeap, PairingBlockHeap, Frontier, FrontierKind, DrainSorted};
+pub use matrix::Matrix;
+pub use distance_matrix::bmssp_distance_matrix;
+pub use k_shortest::{bmssp_k_shortest_paths, reconstruct_path, yen_ksp};
+pub use block_queue::BlockQueue;
+pub use recursive::{bmssp_bounded_multi_source, bmssp_multi_source_bounded, BmsspBoundedResult, BmsspMultiSourceResult};
+pub use pivot::PivotFinder;
+pub use paths::{bmssp_sssp_with_paths, ShortestPathTree};
+pub use loaders::{load_dimacs_gr, load_tsplib, LoadedGraph};
+pub use semiring::{sssp_semiring, Semiring, TropicalSemiring, MaxMinSemiring};
+pub use alt::{astar_sssp, AltIndex};
+pub use centrality::{betweenness, closeness};
+pub use incremental::SsspState;
+pub use layered::layered_sssp;
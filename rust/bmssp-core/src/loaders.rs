@@ -0,0 +1,323 @@
+use crate::csr::CsrGraph;
+use crate::error::{BmsspError, Result};
+
+/// Output of either loader: the CSR graph, weights aligned to `indices`,
+/// and node coordinates when the source format carried them (TSPLIB
+/// coordinate instances; always `None` for DIMACS `.gr` files).
+pub struct LoadedGraph {
+    pub graph: CsrGraph,
+    pub weights: Vec<f32>,
+    pub node_coords: Option<Vec<(f64, f64)>>,
+}
+
+/// Build a CSR graph (and its weight array) from an unordered `(u, v, w)`
+/// edge list via a counting sort on `u`, mirroring [`CsrGraph::transpose`]'s
+/// approach to the same grouping problem.
+fn build_csr_from_edges(n: usize, edges: &[(usize, usize, f32)]) -> Result<(CsrGraph, Vec<f32>)> {
+    let mut indptr = vec![0usize; n + 1];
+    for &(u, _, _) in edges {
+        indptr[u + 1] += 1;
+    }
+    for i in 0..n {
+        indptr[i + 1] += indptr[i];
+    }
+
+    let mut indices = vec![0usize; edges.len()];
+    let mut weights = vec![0f32; edges.len()];
+    let mut cursor = indptr.clone();
+    for &(u, v, w) in edges {
+        let pos = cursor[u];
+        indices[pos] = v;
+        weights[pos] = w;
+        cursor[u] += 1;
+    }
+
+    let graph = CsrGraph::new(n, indptr, indices)?;
+    Ok((graph, weights))
+}
+
+/// Load a DIMACS shortest-path instance (`.gr` format)
+///
+/// Parses the `p sp <n> <m>` problem line and one `a <u> <v> <w>` arc line
+/// per edge; DIMACS vertex ids are 1-indexed and are converted to this
+/// crate's 0-indexed convention. `c`-prefixed comment lines are ignored.
+pub fn load_dimacs_gr(input: &str) -> Result<LoadedGraph> {
+    let mut n: Option<usize> = None;
+    let mut edges = Vec::new();
+
+    for line in input.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('c') {
+            continue;
+        }
+
+        let mut tokens = line.split_whitespace();
+        match tokens.next() {
+            Some("p") => {
+                let _problem_type = tokens.next();
+                let parsed_n = tokens
+                    .next()
+                    .and_then(|s| s.parse::<usize>().ok())
+                    .ok_or_else(|| BmsspError::ParseError("malformed DIMACS problem line".into()))?;
+                n = Some(parsed_n);
+            }
+            Some("a") => {
+                let parse_vertex = |tok: Option<&str>| -> Result<usize> {
+                    tok.and_then(|s| s.parse::<usize>().ok())
+                        .filter(|&v| v >= 1)
+                        .map(|v| v - 1)
+                        .ok_or_else(|| BmsspError::ParseError("malformed DIMACS arc line".into()))
+                };
+                let u = parse_vertex(tokens.next())?;
+                let v = parse_vertex(tokens.next())?;
+                let w: f32 = tokens
+                    .next()
+                    .and_then(|s| s.parse().ok())
+                    .ok_or_else(|| BmsspError::ParseError("malformed DIMACS arc line".into()))?;
+                edges.push((u, v, w));
+            }
+            _ => {}
+        }
+    }
+
+    let n = n.ok_or_else(|| BmsspError::ParseError("missing DIMACS problem line".into()))?;
+    let (graph, weights) = build_csr_from_edges(n, &edges)?;
+    Ok(LoadedGraph {
+        graph,
+        weights,
+        node_coords: None,
+    })
+}
+
+/// Load a TSPLIB instance, materializing a complete directed graph
+///
+/// Supports `NODE_COORD_SECTION` with `EDGE_WEIGHT_TYPE` `EUC_2D` (rounded
+/// Euclidean distance) or `GEO` (TSPLIB's geographic distance formula), and
+/// an explicit `EDGE_WEIGHT_SECTION` in `FULL_MATRIX` format. Other
+/// `EDGE_WEIGHT_TYPE`/`EDGE_WEIGHT_FORMAT` values return a `ParseError`
+/// rather than silently producing wrong weights.
+pub fn load_tsplib(input: &str) -> Result<LoadedGraph> {
+    let mut dimension: usize = 0;
+    let mut weight_type = String::new();
+    let mut weight_format = String::new();
+    let mut node_coords: Vec<(f64, f64)> = Vec::new();
+    let mut explicit_weights: Vec<f32> = Vec::new();
+
+    let mut lines = input.lines().peekable();
+    while let Some(raw_line) = lines.next() {
+        let line = raw_line.trim();
+        if line.is_empty() || line == "EOF" {
+            continue;
+        }
+
+        if let Some(rest) = strip_field(line, "DIMENSION") {
+            dimension = rest
+                .parse()
+                .map_err(|_| BmsspError::ParseError("malformed DIMENSION line".into()))?;
+        } else if let Some(rest) = strip_field(line, "EDGE_WEIGHT_TYPE") {
+            weight_type = rest.to_string();
+        } else if let Some(rest) = strip_field(line, "EDGE_WEIGHT_FORMAT") {
+            weight_format = rest.to_string();
+        } else if line == "NODE_COORD_SECTION" {
+            for _ in 0..dimension {
+                let Some(coord_line) = lines.next() else {
+                    break;
+                };
+                let mut tokens = coord_line.split_whitespace();
+                let _idx = tokens.next();
+                let x: f64 = tokens
+                    .next()
+                    .and_then(|s| s.parse().ok())
+                    .ok_or_else(|| BmsspError::ParseError("malformed NODE_COORD_SECTION entry".into()))?;
+                let y: f64 = tokens
+                    .next()
+                    .and_then(|s| s.parse().ok())
+                    .ok_or_else(|| BmsspError::ParseError("malformed NODE_COORD_SECTION entry".into()))?;
+                node_coords.push((x, y));
+            }
+        } else if line == "EDGE_WEIGHT_SECTION" {
+            let count = dimension * dimension;
+            while explicit_weights.len() < count {
+                let Some(row_line) = lines.next() else {
+                    break;
+                };
+                for tok in row_line.split_whitespace() {
+                    let w: f32 = tok
+                        .parse()
+                        .map_err(|_| BmsspError::ParseError("malformed EDGE_WEIGHT_SECTION entry".into()))?;
+                    explicit_weights.push(w);
+                }
+            }
+        }
+    }
+
+    if dimension == 0 {
+        return Err(BmsspError::ParseError("missing DIMENSION line".into()));
+    }
+
+    let mut edges = Vec::with_capacity(dimension * dimension.saturating_sub(1));
+    if !explicit_weights.is_empty() {
+        if !weight_format.is_empty() && weight_format != "FULL_MATRIX" {
+            return Err(BmsspError::ParseError(format!(
+                "unsupported EDGE_WEIGHT_FORMAT: {}",
+                weight_format
+            )));
+        }
+        for i in 0..dimension {
+            for j in 0..dimension {
+                if i != j {
+                    edges.push((i, j, explicit_weights[i * dimension + j]));
+                }
+            }
+        }
+    } else if !node_coords.is_empty() {
+        for i in 0..dimension {
+            for j in 0..dimension {
+                if i == j {
+                    continue;
+                }
+                let w = match weight_type.as_str() {
+                    "EUC_2D" => euc_2d_distance(node_coords[i], node_coords[j]),
+                    "GEO" => geo_distance(node_coords[i], node_coords[j]),
+                    other => {
+                        return Err(BmsspError::ParseError(format!(
+                            "unsupported EDGE_WEIGHT_TYPE: {}",
+                            other
+                        )))
+                    }
+                };
+                edges.push((i, j, w));
+            }
+        }
+    } else {
+        return Err(BmsspError::ParseError(
+            "TSPLIB instance has neither NODE_COORD_SECTION nor EDGE_WEIGHT_SECTION".into(),
+        ));
+    }
+
+    let (graph, weights) = build_csr_from_edges(dimension, &edges)?;
+    Ok(LoadedGraph {
+        graph,
+        weights,
+        node_coords: if node_coords.is_empty() {
+            None
+        } else {
+            Some(node_coords)
+        },
+    })
+}
+
+/// `"KEY : value"` or `"KEY value"` -> `Some("value")` if `line` names `key`
+fn strip_field<'a>(line: &'a str, key: &str) -> Option<&'a str> {
+    let rest = line.strip_prefix(key)?;
+    Some(rest.trim_start_matches(':').trim())
+}
+
+fn euc_2d_distance(a: (f64, f64), b: (f64, f64)) -> f32 {
+    let dx = a.0 - b.0;
+    let dy = a.1 - b.1;
+    (dx * dx + dy * dy).sqrt().round() as f32
+}
+
+/// TSPLIB's `GEO` edge weight type: latitude/longitude (given as
+/// `DDD.MM` degrees-and-minutes) converted to radians, then the standard
+/// geographic great-circle formula from the TSPLIB spec, truncated to an
+/// integer.
+fn geo_distance(a: (f64, f64), b: (f64, f64)) -> f32 {
+    // TSPLIB's GEO spec mandates this truncated constant rather than a
+    // full-precision PI, so the distances match the reference solvers.
+    #[allow(clippy::approx_constant)]
+    const PI: f64 = 3.141592;
+    const RRR: f64 = 6378.388;
+
+    let to_radians = |v: f64| {
+        let deg = v.trunc();
+        let min = v - deg;
+        PI * (deg + 5.0 * min / 3.0) / 180.0
+    };
+
+    let (lat1, lon1) = (to_radians(a.0), to_radians(a.1));
+    let (lat2, lon2) = (to_radians(b.0), to_radians(b.1));
+
+    let q1 = (lon1 - lon2).cos();
+    let q2 = (lat1 - lat2).cos();
+    let q3 = (lat1 + lat2).cos();
+    let dist = RRR * (0.5 * ((1.0 + q1) * q2 - (1.0 - q1) * q3)).acos() + 1.0;
+    dist.trunc() as f32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_load_dimacs_gr_basic() {
+        let input = "c sample graph\np sp 3 2\na 1 2 5\na 2 3 7\n";
+        let loaded = load_dimacs_gr(input).unwrap();
+        assert_eq!(loaded.graph.num_vertices(), 3);
+        assert_eq!(loaded.graph.num_edges(), 2);
+        assert_eq!(loaded.graph.neighbors(0), &[1]);
+        assert_eq!(loaded.weights[0], 5.0);
+        assert!(loaded.node_coords.is_none());
+    }
+
+    #[test]
+    fn test_load_dimacs_gr_missing_header_errors() {
+        let input = "a 1 2 5\n";
+        assert!(load_dimacs_gr(input).is_err());
+    }
+
+    #[test]
+    fn test_load_tsplib_euc_2d() {
+        let input = "\
+NAME: test
+TYPE: TSP
+DIMENSION: 3
+EDGE_WEIGHT_TYPE: EUC_2D
+NODE_COORD_SECTION
+1 0.0 0.0
+2 3.0 4.0
+3 6.0 8.0
+EOF
+";
+        let loaded = load_tsplib(input).unwrap();
+        assert_eq!(loaded.graph.num_vertices(), 3);
+        assert_eq!(loaded.graph.num_edges(), 6);
+        assert_eq!(loaded.node_coords.as_ref().unwrap().len(), 3);
+
+        // Edge 0 -> 1 is the first outgoing edge of vertex 0.
+        assert_eq!(loaded.weights[0], 5.0);
+    }
+
+    #[test]
+    fn test_load_tsplib_explicit_full_matrix() {
+        let input = "\
+NAME: test
+TYPE: TSP
+DIMENSION: 2
+EDGE_WEIGHT_TYPE: EXPLICIT
+EDGE_WEIGHT_FORMAT: FULL_MATRIX
+EDGE_WEIGHT_SECTION
+0 9
+9 0
+EOF
+";
+        let loaded = load_tsplib(input).unwrap();
+        assert_eq!(loaded.graph.num_vertices(), 2);
+        assert_eq!(loaded.weights, vec![9.0, 9.0]);
+        assert!(loaded.node_coords.is_none());
+    }
+
+    #[test]
+    fn test_load_tsplib_unsupported_weight_type_errors() {
+        let input = "\
+DIMENSION: 2
+EDGE_WEIGHT_TYPE: ATT
+NODE_COORD_SECTION
+1 0.0 0.0
+2 1.0 1.0
+EOF
+";
+        assert!(load_tsplib(input).is_err());
+    }
+}
@@ -0,0 +1,77 @@
+/// Flat row-major matrix, indexable as `m[row]` yielding that row's slice
+///
+/// Backs [`crate::distance_matrix::bmssp_distance_matrix`]'s pairwise cost
+/// table: a single `Vec<T>` plus a stride, rather than a `Vec<Vec<T>>`, so
+/// rows stay contiguous and cheap to hand back to callers (e.g. as one
+/// numpy array on the Python side).
+#[derive(Debug, Clone)]
+pub struct Matrix<T> {
+    data: Vec<T>,
+    stride: usize,
+}
+
+impl<T> Matrix<T> {
+    /// Wrap `data` as a matrix with `stride` columns per row
+    ///
+    /// `data.len()` must be a multiple of `stride` (`stride == 0` is only
+    /// valid for an empty matrix).
+    pub fn new(data: Vec<T>, stride: usize) -> Self {
+        assert!(
+            stride == 0 || data.len().is_multiple_of(stride),
+            "matrix data length must be a multiple of the stride"
+        );
+        Self { data, stride }
+    }
+
+    /// Number of columns per row
+    pub fn stride(&self) -> usize {
+        self.stride
+    }
+
+    /// Number of rows
+    pub fn rows(&self) -> usize {
+        if self.stride == 0 {
+            0
+        } else {
+            self.data.len() / self.stride
+        }
+    }
+
+    /// Flat row-major backing storage
+    pub fn as_slice(&self) -> &[T] {
+        &self.data
+    }
+
+    /// Consume the matrix, returning its flat row-major backing storage
+    pub fn into_vec(self) -> Vec<T> {
+        self.data
+    }
+}
+
+impl<T> std::ops::Index<usize> for Matrix<T> {
+    type Output = [T];
+
+    fn index(&self, row: usize) -> &[T] {
+        let start = row * self.stride;
+        &self.data[start..start + self.stride]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_matrix_row_indexing() {
+        let m = Matrix::new(vec![1, 2, 3, 4, 5, 6], 3);
+        assert_eq!(m.rows(), 2);
+        assert_eq!(&m[0], &[1, 2, 3]);
+        assert_eq!(&m[1], &[4, 5, 6]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_matrix_rejects_non_multiple_length() {
+        let _ = Matrix::new(vec![1, 2, 3], 2);
+    }
+}
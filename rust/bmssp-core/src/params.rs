@@ -10,6 +10,13 @@ pub struct BmsspParams {
     pub k: usize,
     /// Level parameter
     pub l: usize,
+    /// Rank tolerance for approximate-quantile frontier extraction.
+    ///
+    /// `None` (the default) keeps the exact `pop_block` behavior. `Some(eps)`
+    /// opts into [`crate::block_heap::BlockHeap::pop_approx_block`], trading
+    /// a bounded rank error for O(1/eps) memory and avoiding a full sort of
+    /// large frontiers.
+    pub epsilon: Option<f64>,
 }
 
 impl BmsspParams {
@@ -26,12 +33,12 @@ impl BmsspParams {
     /// and block processing size.
     pub fn from_n(n: usize) -> Self {
         if n == 0 {
-            return Self { t: 0, k: 0, l: 0 };
+            return Self { t: 0, k: 0, l: 0, epsilon: None };
         }
-        
+
         if n <= 4 {
             // Very small graphs: use minimal parameters
-            return Self { t: 2, k: 2, l: 1 };
+            return Self { t: 2, k: 2, l: 1, epsilon: None };
         }
         
         let log_n = (n as f64).ln().max(1.0);
@@ -47,8 +54,16 @@ impl BmsspParams {
             t: t.max(2).min(n / 2),  // t should be at most n/2
             k: k.max(2).min(n),       // k should be at most n
             l: l.max(1),
+            epsilon: None,
         }
     }
+
+    /// Opt into approximate-quantile frontier extraction with the given
+    /// rank tolerance (see [`BmsspParams::epsilon`]).
+    pub fn with_epsilon(mut self, epsilon: f64) -> Self {
+        self.epsilon = Some(epsilon);
+        self
+    }
 }
 
 #[cfg(test)]
@@ -68,4 +83,13 @@ mod tests {
         let params = BmsspParams::from_n(10000);
         assert!(params.t > params.k);
     }
+
+    #[test]
+    fn test_params_epsilon_default_and_opt_in() {
+        let params = BmsspParams::from_n(100);
+        assert_eq!(params.epsilon, None);
+
+        let tolerant = params.with_epsilon(0.01);
+        assert_eq!(tolerant.epsilon, Some(0.01));
+    }
 }
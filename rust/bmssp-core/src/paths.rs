@@ -0,0 +1,97 @@
+use num_traits::Float;
+
+use crate::bmssp::bmssp_sssp_with_preds;
+use crate::csr::CsrGraph;
+use crate::error::Result;
+use crate::k_shortest::reconstruct_path;
+
+/// Single-source shortest-path tree: distances plus predecessors from one
+/// `source`, with a `reconstruct` helper for turning a target vertex into
+/// the actual vertex sequence instead of just its distance.
+///
+/// The predecessor array is filled in lock-step with distances by every
+/// BMSSP entry point — `pred[v]` is only overwritten in the same relaxation
+/// step that lowers `dist[v]`, including inside the bounded recursion in
+/// [`crate::bmssp_bounded_multi_source`] — so `reconstruct` is always
+/// consistent with the reported distances.
+pub struct ShortestPathTree<T> {
+    source: usize,
+    distances: Vec<T>,
+    predecessors: Vec<usize>,
+}
+
+impl<T> ShortestPathTree<T>
+where
+    T: Copy,
+{
+    /// Distance from `source` to every vertex
+    pub fn distances(&self) -> &[T] {
+        &self.distances
+    }
+
+    /// Predecessor of every vertex on its shortest path from `source`
+    /// (`usize::MAX` if unreachable)
+    pub fn predecessors(&self) -> &[usize] {
+        &self.predecessors
+    }
+
+    /// Distance from `source` to `target`
+    pub fn distance_to(&self, target: usize) -> T {
+        self.distances[target]
+    }
+
+    /// Walk the predecessor chain from `target` back to `source`, returning
+    /// the vertex sequence in travel order. `None` if `target` is
+    /// unreachable from `source`.
+    pub fn reconstruct(&self, target: usize) -> Option<Vec<usize>> {
+        reconstruct_path(&self.predecessors, self.source, target)
+    }
+}
+
+/// Run single-source BMSSP and return a [`ShortestPathTree`] that can
+/// reconstruct actual paths, not just report distances
+pub fn bmssp_sssp_with_paths<T>(
+    graph: &CsrGraph,
+    weights: &[T],
+    source: usize,
+    enabled: Option<&[bool]>,
+) -> Result<ShortestPathTree<T>>
+where
+    T: Float + Copy + Send + Sync + 'static,
+{
+    let (distances, predecessors) = bmssp_sssp_with_preds(graph, weights, source, enabled)?;
+    Ok(ShortestPathTree {
+        source,
+        distances,
+        predecessors,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::csr::CsrGraph;
+
+    #[test]
+    fn test_shortest_path_tree_reconstructs_chain() {
+        let indptr = vec![0, 1, 2, 3, 3];
+        let indices = vec![1, 2, 3];
+        let graph = CsrGraph::new(4, indptr, indices).unwrap();
+        let weights = vec![1.0f32, 2.0, 3.0];
+
+        let tree = bmssp_sssp_with_paths(&graph, &weights, 0, None).unwrap();
+        assert_eq!(tree.distance_to(3), 6.0);
+        assert_eq!(tree.reconstruct(3), Some(vec![0, 1, 2, 3]));
+    }
+
+    #[test]
+    fn test_shortest_path_tree_unreachable_target() {
+        let indptr = vec![0, 1, 1, 1];
+        let indices = vec![1];
+        let graph = CsrGraph::new(3, indptr, indices).unwrap();
+        let weights = vec![1.0f32];
+
+        let tree = bmssp_sssp_with_paths(&graph, &weights, 0, None).unwrap();
+        assert_eq!(tree.reconstruct(2), None);
+    }
+}
@@ -55,7 +55,7 @@ impl PivotFinder {
         }
         
         while let Some(u) = queue.pop_front() {
-            let (start, end) = graph.edge_range(u);
+            let (start, _end) = graph.edge_range(u);
             for (eid, &v) in graph.neighbors(u).iter().enumerate() {
                 let edge_idx = start + eid;
                 
@@ -0,0 +1,187 @@
+use num_traits::Float;
+
+/// A single summary entry: `value` together with bounds `[rmin, rmax]` on
+/// its true rank within all values inserted so far.
+#[derive(Debug, Clone, Copy)]
+struct Tuple<T> {
+    value: T,
+    rmin: usize,
+    rmax: usize,
+}
+
+/// Epsilon-approximate quantile summary (Greenwald-Khanna / Zhang-Wang style)
+///
+/// Maintains a compressed, value-ordered list of `(value, rmin, rmax)`
+/// tuples so that a quantile query is a single scan of the summary instead
+/// of requiring a full sort of all `N` inserted values. Memory stays
+/// O(1/epsilon) regardless of `N` thanks to periodic compression of
+/// adjacent tuples whose combined rank range is still within tolerance.
+pub struct GkSummary<T> {
+    epsilon: f64,
+    n: usize,
+    tuples: Vec<Tuple<T>>,
+}
+
+impl<T> GkSummary<T>
+where
+    T: Float + Copy,
+{
+    /// Create a new empty summary with the given rank tolerance `epsilon`
+    /// (fraction of `N`, e.g. `0.01` for 1% rank error).
+    pub fn new(epsilon: f64) -> Self {
+        Self {
+            epsilon: epsilon.max(1e-9),
+            n: 0,
+            tuples: Vec::new(),
+        }
+    }
+
+    /// Number of values inserted so far
+    pub fn len(&self) -> usize {
+        self.n
+    }
+
+    /// Whether any values have been inserted
+    pub fn is_empty(&self) -> bool {
+        self.n == 0
+    }
+
+    /// Insert a new value, placing its rank bounds from its neighbors in
+    /// the ordered tuple list.
+    pub fn insert(&mut self, value: T) {
+        let pos = match self
+            .tuples
+            .binary_search_by(|t| t.value.partial_cmp(&value).unwrap_or(std::cmp::Ordering::Equal))
+        {
+            Ok(p) | Err(p) => p,
+        };
+
+        let (rmin, rmax) = if self.tuples.is_empty() {
+            (1, 1)
+        } else if pos == 0 {
+            (1, self.tuples[0].rmax)
+        } else if pos == self.tuples.len() {
+            let prev = self.tuples[pos - 1];
+            (prev.rmin + 1, prev.rmax + 1)
+        } else {
+            let prev = self.tuples[pos - 1];
+            (prev.rmin + 1, self.tuples[pos].rmax)
+        };
+
+        self.tuples.insert(pos, Tuple { value, rmin, rmax });
+
+        // Every tuple after the new one now has one more, smaller element
+        // ranked ahead of it, so its absolute rank bounds both shift up by
+        // one -- without this, older tuples' rmin/rmax stay frozen at their
+        // insertion-time values and massively understate true rank as more
+        // values accumulate ahead of them.
+        for t in &mut self.tuples[pos + 1..] {
+            t.rmin += 1;
+            t.rmax += 1;
+        }
+
+        self.n += 1;
+
+        // Compress periodically rather than after every insert, so the
+        // amortized cost per insert stays low.
+        let compress_period = (1.0 / (2.0 * self.epsilon)).ceil().max(1.0) as usize;
+        if self.n.is_multiple_of(compress_period) {
+            self.compress();
+        }
+    }
+
+    /// Merge adjacent tuples whose combined rank range is still within
+    /// `2 * epsilon * N`, bounding the summary to O(1/epsilon) tuples.
+    ///
+    /// Never drops the first tuple: it holds the exact global minimum, and
+    /// merging it away would lose that value entirely rather than just
+    /// widening its rank bounds (the last tuple is already safe, since the
+    /// loop only ever removes `tuples[i]` for `i < len - 1`).
+    fn compress(&mut self) {
+        if self.tuples.len() < 3 {
+            return;
+        }
+        let band_width = (2.0 * self.epsilon * self.n as f64).floor() as usize;
+        let mut i = 1;
+        while i + 1 < self.tuples.len() {
+            let merged_rmin = self.tuples[i].rmin;
+            let merged_rmax = self.tuples[i + 1].rmax;
+            if merged_rmax.saturating_sub(merged_rmin) <= band_width {
+                // Merging into tuples[i + 1] must carry tuples[i]'s rank
+                // mass forward, or the survivor's rmin stays stale and
+                // understates the true rank of every value at or above it.
+                self.tuples[i + 1].rmin = merged_rmin;
+                self.tuples.remove(i);
+            } else {
+                i += 1;
+            }
+        }
+    }
+
+    /// Query the value at approximate quantile `phi` (in `[0, 1]`), with
+    /// rank error bounded by `epsilon * N`.
+    ///
+    /// Per the standard GK query rule: scan for the first tuple whose
+    /// `rmax` exceeds `phi * N + epsilon * N` and return the *previous*
+    /// tuple's value, falling back to the last tuple if none exceeds it.
+    pub fn quantile(&self, phi: f64) -> Option<T> {
+        let first = self.tuples.first()?;
+        let phi = phi.clamp(0.0, 1.0);
+        let threshold = phi * self.n as f64 + self.epsilon * self.n as f64;
+
+        let mut prev_value = first.value;
+        for t in &self.tuples {
+            if t.rmax as f64 > threshold {
+                return Some(prev_value);
+            }
+            prev_value = t.value;
+        }
+        Some(prev_value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_empty_summary() {
+        let summary = GkSummary::<f32>::new(0.01);
+        assert!(summary.is_empty());
+        assert_eq!(summary.quantile(0.5), None);
+    }
+
+    #[test]
+    fn test_median_exact_small() {
+        let mut summary = GkSummary::new(0.001);
+        for v in [3.0f32, 1.0, 4.0, 1.0, 5.0, 9.0, 2.0, 6.0] {
+            summary.insert(v);
+        }
+        assert_eq!(summary.len(), 8);
+        // With tight epsilon the median estimate should land near the true
+        // sorted middle (3.0 or 4.0 for this 8-element set).
+        let median = summary.quantile(0.5).unwrap();
+        assert!(median >= 2.0 && median <= 5.0);
+    }
+
+    #[test]
+    fn test_min_and_max_quantiles() {
+        let mut summary = GkSummary::new(0.01);
+        for v in 0..100 {
+            summary.insert(v as f32);
+        }
+        assert_eq!(summary.quantile(0.0), Some(0.0));
+        assert_eq!(summary.quantile(1.0), Some(99.0));
+    }
+
+    #[test]
+    fn test_compression_bounds_summary_size() {
+        let epsilon = 0.05;
+        let mut summary = GkSummary::new(epsilon);
+        for v in 0..1000 {
+            summary.insert(v as f32);
+        }
+        // O(1/epsilon) tuples regardless of N
+        assert!(summary.tuples.len() <= (1.0 / epsilon) as usize * 4);
+    }
+}
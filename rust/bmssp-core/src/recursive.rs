@@ -0,0 +1,428 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashSet};
+
+use num_traits::Float;
+
+use crate::block_queue::BlockQueue;
+use crate::csr::CsrGraph;
+use crate::error::Result;
+use crate::ordered_float::OrderedFloat;
+use crate::params::BmsspParams;
+use crate::pivot::PivotFinder;
+
+/// Result of a top-level [`bmssp_bounded_multi_source`] run
+pub struct BmsspBoundedResult<T> {
+    /// Final distance estimate for every vertex (only entries `< bound` are
+    /// guaranteed finalized; the rest may still be `T::infinity()` or a
+    /// non-optimal tentative value)
+    pub distances: Vec<T>,
+    /// Predecessor for every finalized vertex (`usize::MAX` if none)
+    pub predecessors: Vec<usize>,
+    /// The vertices BMSSP actually finalized (`U` in the paper's notation)
+    pub completed: Vec<usize>,
+    /// The returned bound `B'` — every vertex in `completed` has
+    /// `distances[v] < bound`, and every vertex with `distances[v] < bound`
+    /// not in `completed` is unreachable within the search
+    pub bound: T,
+}
+
+/// Mutable search state threaded through the recursive BMSSP calls
+struct BmsspCtx<'a, T> {
+    graph: &'a CsrGraph,
+    weights: &'a [T],
+    enabled: Option<&'a [bool]>,
+    dist: Vec<T>,
+    pred: Vec<usize>,
+    /// Every vertex whose `dist` was ever improved by [`Self::relax`], in
+    /// relaxation order, duplicates included. `bmssp` scans the slice a
+    /// recursive call appended to catch vertices that got a correct
+    /// tentative distance deep inside that call but were excluded from its
+    /// returned `settled` set (e.g. `base_case`'s boundary `(k+1)`th pop) —
+    /// without this, such a vertex's own outgoing edges are never explored
+    /// again and the search silently stalls short of the true bound.
+    touched: Vec<usize>,
+}
+
+impl<'a, T> BmsspCtx<'a, T>
+where
+    T: Float + Copy,
+{
+    fn relax(&mut self, u: usize, bound: T) -> Vec<(usize, T)> {
+        let mut relaxed = Vec::new();
+        let (start, _end) = self.graph.edge_range(u);
+        for (eid, &v) in self.graph.neighbors(u).iter().enumerate() {
+            let edge_idx = start + eid;
+            if let Some(mask) = self.enabled {
+                if !mask[edge_idx] {
+                    continue;
+                }
+            }
+            let new_dist = self.dist[u] + self.weights[edge_idx];
+            if new_dist < bound && new_dist < self.dist[v] {
+                self.dist[v] = new_dist;
+                self.pred[v] = u;
+                self.touched.push(v);
+                relaxed.push((v, new_dist));
+            }
+        }
+        relaxed
+    }
+
+    /// Base case (`l == 0`): a mini-Dijkstra bounded by `B`, stopped as
+    /// soon as `k + 1` vertices have been popped. For an admissible
+    /// bound, the first `k` pops are finalized; the `(k+1)`th pop's
+    /// distance becomes the tightened bound `B'`.
+    fn base_case(&mut self, bound: T, sources: &[usize], k: usize) -> (T, Vec<usize>) {
+        let mut heap: BinaryHeap<Reverse<(OrderedFloat<T>, usize)>> = BinaryHeap::new();
+        for &s in sources {
+            if self.dist[s] < bound {
+                heap.push(Reverse((OrderedFloat(self.dist[s]), s)));
+            }
+        }
+
+        let mut settled = HashSet::new();
+        let mut popped = Vec::new();
+
+        while let Some(Reverse((OrderedFloat(d), u))) = heap.pop() {
+            if settled.contains(&u) || d > self.dist[u] {
+                continue;
+            }
+            settled.insert(u);
+            popped.push(u);
+            if popped.len() > k {
+                break;
+            }
+
+            for (v, new_dist) in self.relax(u, bound) {
+                heap.push(Reverse((OrderedFloat(new_dist), v)));
+            }
+        }
+
+        if popped.len() > k {
+            let extra = popped.pop().expect("just checked len > k");
+            (self.dist[extra], popped)
+        } else {
+            (bound, popped)
+        }
+    }
+
+    /// The recursive bounded multi-source primitive itself:
+    /// `bmssp(level, bound, source_set) -> (bound', completed_set)`.
+    ///
+    /// At `level == 0` this is [`Self::base_case`]. At higher levels it
+    /// shrinks `sources` to a pivot set `P` via [`PivotFinder::find_pivots`],
+    /// repeatedly pulls the smallest block of pivots from the [`BlockQueue`]
+    /// `D`, recurses one level down with a tighter bound, and
+    /// batch-prepends newly relaxed vertices whose tentative distance beats
+    /// the returned `B'` so they're picked up first on the next pull.
+    fn bmssp(&mut self, level: usize, bound: T, sources: &[usize], params: &BmsspParams) -> (T, Vec<usize>) {
+        if level == 0 || sources.is_empty() {
+            return self.base_case(bound, sources, params.k);
+        }
+
+        let (pivots, candidates) = PivotFinder::find_pivots(
+            self.graph,
+            self.weights,
+            &self.dist,
+            self.enabled,
+            bound,
+            params,
+        );
+
+        if pivots.is_empty() {
+            let completed = candidates
+                .into_iter()
+                .filter(|&x| self.dist[x] < bound)
+                .collect();
+            return (bound, completed);
+        }
+
+        let exponent = |e: usize| e.min(62);
+        let batch_size = 1usize << exponent((level - 1) * params.t.max(1));
+        let target_count = params.k.saturating_mul(1usize << exponent(level * params.t.max(1)));
+
+        let mut queue: BlockQueue<T> = BlockQueue::new(batch_size.max(1));
+        for &p in &pivots {
+            queue.insert(p, self.dist[p]);
+        }
+
+        let mut completed: HashSet<usize> = HashSet::new();
+        let mut last_bound_prime = bound;
+
+        while completed.len() < target_count.max(1) && !queue.is_empty() {
+            let (batch, next_key) = queue.pull(batch_size.max(1));
+            let sub_sources: Vec<usize> = batch.iter().map(|&(v, _)| v).collect();
+            let sub_bound = next_key.unwrap_or(bound);
+
+            let touched_start = self.touched.len();
+            let (bound_prime, settled) = self.bmssp(level - 1, sub_bound, &sub_sources, params);
+            last_bound_prime = bound_prime;
+            let settled_set: HashSet<usize> = settled.iter().copied().collect();
+            completed.extend(settled.iter().copied());
+
+            let mut carry_forward = Vec::new();
+            for &u in &settled {
+                for (v, new_dist) in self.relax(u, bound) {
+                    if new_dist >= bound_prime && new_dist < sub_bound {
+                        queue.insert(v, new_dist);
+                    } else if new_dist >= sub_bound && new_dist < bound {
+                        carry_forward.push((v, new_dist));
+                    }
+                }
+            }
+
+            // Anything the recursive call touched but didn't settle (its own
+            // boundary pop, or a pivot it was handed but never got to) still
+            // holds a correct tentative distance and still has unexplored
+            // outgoing edges — requeue it for another pass rather than
+            // silently dropping it once it falls out of scope.
+            let mut requeued: HashSet<usize> = HashSet::new();
+            for idx in touched_start..self.touched.len() {
+                let v = self.touched[idx];
+                if settled_set.contains(&v) || !requeued.insert(v) {
+                    continue;
+                }
+                if self.dist[v] < bound {
+                    carry_forward.push((v, self.dist[v]));
+                }
+            }
+            for &v in &sub_sources {
+                if !settled_set.contains(&v) && self.dist[v] < bound && requeued.insert(v) {
+                    carry_forward.push((v, self.dist[v]));
+                }
+            }
+            queue.batch_prepend(carry_forward);
+        }
+
+        let bound_prime = if queue.is_empty() { bound } else { last_bound_prime };
+        for x in candidates {
+            if self.dist[x] < bound_prime {
+                completed.insert(x);
+            }
+        }
+
+        (bound_prime, completed.into_iter().collect())
+    }
+}
+
+/// Run the recursive bounded multi-source BMSSP core from `sources`
+///
+/// This is the actual sorting-barrier-avoiding primitive that
+/// [`crate::bmssp_sssp`] and friends collapse into a flat block-based
+/// single/multi-source search: `bmssp(level, bound, source_set) -> (B',
+/// completed_set)`, recursing through the `l` levels [`BmsspParams::from_n`]
+/// picks for `n` (`ceil(0.8 ln n)` for `n > 4`, independent of `t`). Exposed
+/// directly so callers can do target-bounded queries — stop once a vertex's
+/// distance is known finalized — which the distances-only entry points
+/// can't express.
+///
+/// `distances`/`predecessors` on the returned [`BmsspBoundedResult`] are
+/// only guaranteed optimal for vertices in `completed` (those with
+/// `distance < bound`); unreached vertices retain `T::infinity()`.
+pub fn bmssp_bounded_multi_source<T>(
+    graph: &CsrGraph,
+    weights: &[T],
+    sources: &[usize],
+    enabled: Option<&[bool]>,
+) -> Result<BmsspBoundedResult<T>>
+where
+    T: Float + Copy,
+{
+    let n = graph.num_vertices();
+    let mut ctx = BmsspCtx {
+        graph,
+        weights,
+        enabled,
+        dist: vec![T::infinity(); n],
+        pred: vec![usize::MAX; n],
+        touched: Vec::new(),
+    };
+    for &s in sources {
+        ctx.dist[s] = T::zero();
+        ctx.pred[s] = s;
+    }
+
+    let params = BmsspParams::from_n(n);
+    let (bound, completed) = ctx.bmssp(params.l, T::infinity(), sources, &params);
+
+    Ok(BmsspBoundedResult {
+        distances: ctx.dist,
+        predecessors: ctx.pred,
+        completed,
+        bound,
+    })
+}
+
+/// Result of a top-level [`bmssp_multi_source_bounded`] run
+pub struct BmsspMultiSourceResult<T> {
+    /// Minimum distance from any source to each vertex, capped at `bound`
+    /// (vertices never brought under the bound stay at `T::infinity()`)
+    pub distances: Vec<T>,
+    /// Predecessor for every finalized vertex (`usize::MAX` if none)
+    pub predecessors: Vec<usize>,
+    /// The vertices BMSSP actually finalized (`U` in the paper's notation)
+    pub completed: Vec<usize>,
+    /// The returned bound `B'`, see [`BmsspBoundedResult::bound`]
+    pub bound: T,
+    /// The pivot set `PivotFinder::find_pivots` selected at the top level
+    /// (`level = params.l`) before any recursion — the "large subtree
+    /// roots" that seed the block queue driving the rest of the search
+    pub pivots: Vec<usize>,
+}
+
+/// Batched multi-source BMSSP with a caller-supplied bound and parameter
+/// schedule, exposing the top-level pivot set
+///
+/// Where [`bmssp_bounded_multi_source`] always starts from `T::infinity()`
+/// and derives its [`BmsspParams`] from `n`, this entry point takes both
+/// from the caller, so it can be used as a bounded distance transform (cap
+/// the search at a known radius) or replayed with a fixed parameter
+/// schedule across related queries. All `sources` are seeded at distance
+/// zero and share the same `dist`/`pred` arrays, so relaxation naturally
+/// keeps the elementwise minimum across sources as the recursion narrows
+/// in on each pivot's region.
+///
+/// Named `bmssp_multi_source_bounded` rather than `bmssp_multi_source`
+/// because the latter is already taken by [`crate::bmssp::bmssp_multi_source`],
+/// which tracks per-source offsets and owning source rather than an
+/// explicit caller-supplied bound.
+pub fn bmssp_multi_source_bounded<T>(
+    graph: &CsrGraph,
+    weights: &[T],
+    sources: &[usize],
+    bound: T,
+    params: &BmsspParams,
+    enabled: Option<&[bool]>,
+) -> Result<BmsspMultiSourceResult<T>>
+where
+    T: Float + Copy,
+{
+    let n = graph.num_vertices();
+    let mut ctx = BmsspCtx {
+        graph,
+        weights,
+        enabled,
+        dist: vec![T::infinity(); n],
+        pred: vec![usize::MAX; n],
+        touched: Vec::new(),
+    };
+    for &s in sources {
+        ctx.dist[s] = T::zero();
+        ctx.pred[s] = s;
+    }
+
+    let (pivots, _candidates) =
+        PivotFinder::find_pivots(graph, weights, &ctx.dist, enabled, bound, params);
+
+    let (bound_prime, completed) = ctx.bmssp(params.l, bound, sources, params);
+
+    Ok(BmsspMultiSourceResult {
+        distances: ctx.dist,
+        predecessors: ctx.pred,
+        completed,
+        bound: bound_prime,
+        pivots,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::csr::CsrGraph;
+
+    #[test]
+    fn test_bmssp_bounded_multi_source_chain() {
+        let indptr = vec![0, 1, 2, 3, 4, 4];
+        let indices = vec![1, 2, 3, 4];
+        let graph = CsrGraph::new(5, indptr, indices).unwrap();
+        let weights = vec![1.0f32, 1.0, 1.0, 1.0];
+
+        let result = bmssp_bounded_multi_source(&graph, &weights, &[0], None).unwrap();
+        assert_eq!(result.distances, vec![0.0, 1.0, 2.0, 3.0, 4.0]);
+        for v in 0..5 {
+            assert!(result.completed.contains(&v));
+        }
+    }
+
+    #[test]
+    fn test_bmssp_bounded_multi_source_disjoint_seeds() {
+        let indptr = vec![0, 1, 2, 2, 3, 3];
+        let indices = vec![1, 2, 4];
+        let graph = CsrGraph::new(5, indptr, indices).unwrap();
+        let weights = vec![1.0f32, 1.0, 1.0];
+
+        let result = bmssp_bounded_multi_source(&graph, &weights, &[0, 3], None).unwrap();
+        assert_eq!(result.distances[0], 0.0);
+        assert_eq!(result.distances[1], 1.0);
+        assert_eq!(result.distances[2], 2.0);
+        assert_eq!(result.distances[3], 0.0);
+        assert_eq!(result.distances[4], 1.0);
+    }
+
+    #[test]
+    fn test_bmssp_bounded_multi_source_unreachable_vertex_stays_infinite() {
+        let indptr = vec![0, 1, 1, 1];
+        let indices = vec![1];
+        let graph = CsrGraph::new(3, indptr, indices).unwrap();
+        let weights = vec![1.0f32];
+
+        let result = bmssp_bounded_multi_source(&graph, &weights, &[0], None).unwrap();
+        assert_eq!(result.distances[0], 0.0);
+        assert_eq!(result.distances[1], 1.0);
+        assert!(result.distances[2].is_infinite());
+        assert!(!result.completed.contains(&2));
+    }
+
+    #[test]
+    fn test_bmssp_multi_source_bounded_matches_multi_source_dijkstra() {
+        // Two disjoint seeds, 0 and 5, feeding into a shared diamond at the
+        // end (3 and 4 both reach 6), so the multi-source minimum must come
+        // from whichever seed is actually closer at each vertex.
+        let indptr = vec![0, 1, 2, 3, 4, 5, 6, 6];
+        let indices = vec![1, 2, 6, 4, 6, 6];
+        let graph = CsrGraph::new(7, indptr, indices).unwrap();
+        let weights = vec![1.0f32, 1.0, 10.0, 1.0, 1.0, 1.0];
+
+        let params = BmsspParams::from_n(7);
+        let result =
+            bmssp_multi_source_bounded(&graph, &weights, &[0, 5], f32::INFINITY, &params, None)
+                .unwrap();
+
+        // Baseline: multi-source Dijkstra via a virtual zero-weight super-source
+        // (vertex 7) with edges to every seed.
+        let mut super_indptr = vec![0usize; 9];
+        let mut super_indices = Vec::new();
+        let mut super_weights = Vec::new();
+        for v in 0..7 {
+            let (start, end) = graph.edge_range(v);
+            for eid in start..end {
+                super_indices.push(graph.indices()[eid]);
+                super_weights.push(weights[eid]);
+            }
+            super_indptr[v + 1] = super_indices.len();
+        }
+        for &s in &[0usize, 5] {
+            super_indices.push(s);
+            super_weights.push(0.0f32);
+        }
+        super_indptr[8] = super_indices.len();
+        let super_graph = CsrGraph::new(8, super_indptr, super_indices).unwrap();
+        let (baseline, _) =
+            crate::dijkstra::dijkstra_sssp_with_preds(&super_graph, &super_weights, 7, None)
+                .unwrap();
+
+        for v in 0..7 {
+            assert_eq!(result.distances[v], baseline[v]);
+        }
+        // Every finalized vertex should actually have beaten the bound.
+        for &v in &result.completed {
+            assert!(result.distances[v] < f32::INFINITY);
+        }
+        // The top-level pivot set this entry point exposes should be a
+        // real, non-empty subset of the reachable vertices.
+        assert!(!result.pivots.is_empty());
+        for &p in &result.pivots {
+            assert!(p < 7);
+        }
+    }
+}
@@ -0,0 +1,242 @@
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+use std::marker::PhantomData;
+
+use num_traits::Float;
+
+use crate::csr::CsrGraph;
+use crate::error::Result;
+use crate::validation;
+
+/// An algebraic semiring over edge weights, parameterizing path relaxation
+///
+/// `plus` combines two alternative path costs when choosing between them
+/// (`min` for shortest-path, `max` for widest-path); `times` combines costs
+/// along a single path (`+` for shortest-path, `min` for widest-path's
+/// bottleneck capacity). `dominates(a, b)` orders the frontier so the best
+/// value so far is always extracted first — ascending for the tropical
+/// semiring, descending for max-min. Relaxation itself stores
+/// `plus(dist[v], candidate)` and counts any change as an improvement.
+pub trait Semiring: Copy {
+    type Weight: Copy + PartialEq;
+
+    /// Identity element for `plus` — "no path found yet"
+    fn zero() -> Self::Weight;
+    /// Identity element for `times` — the cost of the empty path
+    fn one() -> Self::Weight;
+    /// Combine two alternative path costs, keeping the better one
+    fn plus(a: Self::Weight, b: Self::Weight) -> Self::Weight;
+    /// Combine costs along a path
+    fn times(a: Self::Weight, b: Self::Weight) -> Self::Weight;
+    /// True if `a` is at least as good as `b` under this semiring's order
+    fn dominates(a: Self::Weight, b: Self::Weight) -> bool;
+}
+
+/// Tropical semiring: ordinary shortest-path distances (`plus = min`,
+/// `times = +`). Passed as `S` to [`sssp_semiring`], it reproduces
+/// [`crate::bmssp_sssp`]'s distances for the same inputs; `S` has no
+/// default, so callers always name the semiring.
+#[derive(Debug, Clone, Copy)]
+pub struct TropicalSemiring<T>(PhantomData<T>);
+
+impl<T> Semiring for TropicalSemiring<T>
+where
+    T: Float + Copy,
+{
+    type Weight = T;
+
+    fn zero() -> T {
+        T::infinity()
+    }
+
+    fn one() -> T {
+        T::zero()
+    }
+
+    fn plus(a: T, b: T) -> T {
+        if a < b {
+            a
+        } else {
+            b
+        }
+    }
+
+    fn times(a: T, b: T) -> T {
+        a + b
+    }
+
+    fn dominates(a: T, b: T) -> bool {
+        a <= b
+    }
+}
+
+/// Max-min (widest-path / bottleneck) semiring: `plus = max`, `times =
+/// min`. Edge "weights" here are capacities/reliabilities, and the
+/// resulting "distance" to each vertex is the bottleneck (minimum edge)
+/// value along the best (maximum-bottleneck) path from the source —
+/// exactly maximum-reliability routing when weights are per-edge
+/// reliabilities in `[0, 1]`.
+#[derive(Debug, Clone, Copy)]
+pub struct MaxMinSemiring<T>(PhantomData<T>);
+
+impl<T> Semiring for MaxMinSemiring<T>
+where
+    T: Float + Copy,
+{
+    type Weight = T;
+
+    fn zero() -> T {
+        T::neg_infinity()
+    }
+
+    fn one() -> T {
+        T::infinity()
+    }
+
+    fn plus(a: T, b: T) -> T {
+        if a > b {
+            a
+        } else {
+            b
+        }
+    }
+
+    fn times(a: T, b: T) -> T {
+        if a < b {
+            a
+        } else {
+            b
+        }
+    }
+
+    fn dominates(a: T, b: T) -> bool {
+        a >= b
+    }
+}
+
+struct Entry<S: Semiring>(S::Weight, usize);
+
+impl<S: Semiring> PartialEq for Entry<S> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0 && self.1 == other.1
+    }
+}
+
+impl<S: Semiring> Eq for Entry<S> {}
+
+impl<S: Semiring> PartialOrd for Entry<S> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<S: Semiring> Ord for Entry<S> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        if self.0 == other.0 {
+            Ordering::Equal
+        } else if S::dominates(self.0, other.0) {
+            Ordering::Greater
+        } else {
+            Ordering::Less
+        }
+    }
+}
+
+/// Single-source search generalized over a [`Semiring`]: the same CSR
+/// edge scan as every other entry point in this crate, driven by its own
+/// `BinaryHeap` frontier ordered by `dominates` rather than by the block
+/// frontier the BMSSP entry points use. With `S` = [`TropicalSemiring`] it reproduces
+/// [`crate::bmssp_sssp`]; [`MaxMinSemiring`] instead computes widest-path
+/// (bottleneck) distances.
+pub fn sssp_semiring<S>(
+    graph: &CsrGraph,
+    weights: &[S::Weight],
+    source: usize,
+    enabled: Option<&[bool]>,
+) -> Result<Vec<S::Weight>>
+where
+    S: Semiring,
+{
+    validation::validate_source(graph, source)?;
+    validation::validate_weights_len(graph, weights.len())?;
+    if let Some(mask) = enabled {
+        validation::validate_enabled_mask(graph.num_edges(), mask)?;
+    }
+
+    let n = graph.num_vertices();
+    let mut dist = vec![S::zero(); n];
+    dist[source] = S::one();
+    let mut settled = vec![false; n];
+
+    let mut heap: BinaryHeap<Entry<S>> = BinaryHeap::new();
+    heap.push(Entry(dist[source], source));
+
+    while let Some(Entry(d, u)) = heap.pop() {
+        if settled[u] || d != dist[u] {
+            continue;
+        }
+        settled[u] = true;
+
+        let (start, _end) = graph.edge_range(u);
+        for (eid, &v) in graph.neighbors(u).iter().enumerate() {
+            let edge_idx = start + eid;
+            if let Some(mask) = enabled {
+                if !mask[edge_idx] {
+                    continue;
+                }
+            }
+
+            let candidate = S::times(d, weights[edge_idx]);
+            let combined = S::plus(dist[v], candidate);
+            if combined != dist[v] {
+                dist[v] = combined;
+                heap.push(Entry(combined, v));
+            }
+        }
+    }
+
+    Ok(dist)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bmssp_sssp;
+
+    #[test]
+    fn test_tropical_semiring_matches_bmssp_sssp() {
+        let indptr = vec![0, 2, 3, 3];
+        let indices = vec![1, 2, 2];
+        let graph = CsrGraph::new(3, indptr, indices).unwrap();
+        let weights = vec![1.0f32, 5.0, 1.0];
+
+        let dist = sssp_semiring::<TropicalSemiring<f32>>(&graph, &weights, 0, None).unwrap();
+        let reference = bmssp_sssp(&graph, &weights, 0, None).unwrap();
+        assert_eq!(dist, reference);
+    }
+
+    #[test]
+    fn test_max_min_semiring_widest_path() {
+        // Two routes from 0 to 3: via 1 (bottleneck min(10,2)=2) and via 2
+        // (bottleneck min(4,6)=4). The widest path should pick the route
+        // through 2, with bottleneck 4.
+        let indptr = vec![0, 2, 3, 4, 4];
+        let indices = vec![1, 2, 3, 3];
+        let graph = CsrGraph::new(4, indptr, indices).unwrap();
+        let weights = vec![10.0f32, 4.0, 2.0, 6.0];
+
+        let dist = sssp_semiring::<MaxMinSemiring<f32>>(&graph, &weights, 0, None).unwrap();
+        assert_eq!(dist[3], 4.0);
+    }
+
+    #[test]
+    fn test_max_min_semiring_unreachable_is_neg_infinity() {
+        let indptr = vec![0, 1, 1, 1];
+        let indices = vec![1];
+        let graph = CsrGraph::new(3, indptr, indices).unwrap();
+        let weights = vec![5.0f32];
+
+        let dist = sssp_semiring::<MaxMinSemiring<f32>>(&graph, &weights, 0, None).unwrap();
+        assert_eq!(dist[2], f32::NEG_INFINITY);
+    }
+}
@@ -1,3 +1,4 @@
+use crate::bitset::EnabledMask;
 use crate::error::{BmsspError, Result};
 use crate::csr::CsrGraph;
 
@@ -19,7 +20,7 @@ pub fn validate_weights<T>(weights: &[T]) -> Result<()>
 where
     T: Copy + PartialOrd + num_traits::Float,
 {
-    for (i, &w) in weights.iter().enumerate() {
+    for &w in weights {
         if !w.is_finite() {
             return Err(BmsspError::NonFiniteWeight);
         }
@@ -41,6 +42,25 @@ pub fn validate_source(graph: &CsrGraph, source: usize) -> Result<()> {
     Ok(())
 }
 
+/// Validate that every seed vertex in a multi-source query is in range
+pub fn validate_sources(graph: &CsrGraph, sources: &[usize]) -> Result<()> {
+    for &source in sources {
+        validate_source(graph, source)?;
+    }
+    Ok(())
+}
+
+/// Validate that a per-source initial distance array matches the source count
+pub fn validate_source_dist_len(num_sources: usize, dist_len: usize) -> Result<()> {
+    if dist_len != num_sources {
+        return Err(BmsspError::InvalidSourceDist {
+            expected: num_sources,
+            actual: dist_len,
+        });
+    }
+    Ok(())
+}
+
 /// Validate that enabled mask length matches edge count
 pub fn validate_enabled_mask(num_edges: usize, enabled: &[bool]) -> Result<()> {
     if enabled.len() != num_edges {
@@ -51,3 +71,14 @@ pub fn validate_enabled_mask(num_edges: usize, enabled: &[bool]) -> Result<()> {
     }
     Ok(())
 }
+
+/// Validate that a bit-packed enabled mask's logical length matches edge count
+pub fn validate_enabled_mask_bits(num_edges: usize, enabled: &EnabledMask) -> Result<()> {
+    if enabled.len() != num_edges {
+        return Err(BmsspError::InvalidEnabledMask {
+            expected: num_edges,
+            actual: enabled.len(),
+        });
+    }
+    Ok(())
+}
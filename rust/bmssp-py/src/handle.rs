@@ -0,0 +1,67 @@
+use pyo3::prelude::*;
+use numpy::PyReadonlyArray1;
+use bmssp_core::CsrGraph;
+
+use crate::sssp::{build_graph, query_sssp_f32, query_sssp_f64};
+
+/// A validated CSR graph held across many queries.
+///
+/// `sssp_f32_csr`/`sssp_f64_csr` re-parse and re-validate `indptr`/`indices`
+/// on every call, which dominates cost for workloads that issue many
+/// queries (different sources, weights, or enabled masks) against the same
+/// fixed topology. `CsrHandle` builds and validates the graph once up
+/// front and exposes the same query surface as methods, so repeated calls
+/// skip straight to solving.
+#[pyclass]
+pub struct CsrHandle {
+    graph: CsrGraph,
+}
+
+#[pymethods]
+impl CsrHandle {
+    #[new]
+    fn new(indptr: PyReadonlyArray1<i64>, indices: PyReadonlyArray1<i64>) -> PyResult<Self> {
+        let graph = build_graph(&indptr, &indices)?;
+        Ok(Self { graph })
+    }
+
+    fn num_vertices(&self) -> usize {
+        self.graph.num_vertices()
+    }
+
+    fn num_edges(&self) -> usize {
+        self.graph.num_edges()
+    }
+
+    #[pyo3(signature = (weights, source, source_dist = None, enabled = None, return_pred = false, target = None))]
+    #[allow(clippy::too_many_arguments)]
+    fn sssp_f32(
+        &self,
+        py: Python,
+        weights: PyReadonlyArray1<f32>,
+        source: &Bound<'_, PyAny>,
+        source_dist: Option<PyReadonlyArray1<f32>>,
+        enabled: Option<PyReadonlyArray1<u8>>,
+        return_pred: bool,
+        target: Option<usize>,
+    ) -> PyResult<PyObject> {
+        let weights_slice = weights.as_slice()?;
+        query_sssp_f32(py, &self.graph, weights_slice, source, source_dist, enabled, return_pred, target)
+    }
+
+    #[pyo3(signature = (weights, source, source_dist = None, enabled = None, return_pred = false, target = None))]
+    #[allow(clippy::too_many_arguments)]
+    fn sssp_f64(
+        &self,
+        py: Python,
+        weights: PyReadonlyArray1<f64>,
+        source: &Bound<'_, PyAny>,
+        source_dist: Option<PyReadonlyArray1<f64>>,
+        enabled: Option<PyReadonlyArray1<u8>>,
+        return_pred: bool,
+        target: Option<usize>,
+    ) -> PyResult<PyObject> {
+        let weights_slice = weights.as_slice()?;
+        query_sssp_f64(py, &self.graph, weights_slice, source, source_dist, enabled, return_pred, target)
+    }
+}
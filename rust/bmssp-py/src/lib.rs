@@ -1,10 +1,13 @@
 use pyo3::prelude::*;
 
 mod sssp;
+mod handle;
 
 #[pymodule]
 fn _bmssp(_py: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(sssp::sssp_f32_csr, m)?)?;
     m.add_function(wrap_pyfunction!(sssp::sssp_f64_csr, m)?)?;
+    m.add_function(wrap_pyfunction!(sssp::sssp_i64_csr, m)?)?;
+    m.add_class::<handle::CsrHandle>()?;
     Ok(())
 }
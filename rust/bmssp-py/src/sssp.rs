@@ -1,37 +1,88 @@
 use pyo3::prelude::*;
 use pyo3::types::PyDict;
-use numpy::{PyReadonlyArray1, IntoPyArray};
-use bmssp_core::{CsrGraph, bmssp_sssp_with_preds, validation};
+use numpy::{PyArray2, PyReadonlyArray1, PyReadonlyArray2, IntoPyArray};
+use rayon::prelude::*;
+use bmssp_core::{CsrGraph, CsrGraphView, bmssp_sssp_with_preds_multi, bidirectional_sssp, dijkstra_sssp_bucket_with_preds, validation, Result as BmsspResult};
+
+/// Parse the `source` argument, which may be a single vertex index or a
+/// 1-D numpy array of seed vertices for a bounded multi-source query.
+pub(crate) fn parse_sources(source: &Bound<'_, PyAny>) -> PyResult<Vec<usize>> {
+    if let Ok(single) = source.extract::<usize>() {
+        return Ok(vec![single]);
+    }
+    let seeds: PyReadonlyArray1<i64> = source.extract()?;
+    Ok(seeds.as_slice()?.iter().map(|&x| x as usize).collect())
+}
+
+/// If `source` is a 2-D numpy array of shape `[q, s]`, parse it into `q`
+/// independent source rows (each a set of up to `s` seed vertices) for a
+/// batched query. Honors numpy strides via `as_array` so callers can pass
+/// non-contiguous column slices without forcing a copy. Returns `None` if
+/// `source` is not 2-D, so callers fall back to the single-query path.
+pub(crate) fn parse_source_rows(source: &Bound<'_, PyAny>) -> PyResult<Option<Vec<Vec<usize>>>> {
+    let Ok(rows) = source.extract::<PyReadonlyArray2<i64>>() else {
+        return Ok(None);
+    };
+    let view = rows.as_array();
+    Ok(Some(
+        view.rows()
+            .into_iter()
+            .map(|row| row.iter().map(|&x| x as usize).collect())
+            .collect(),
+    ))
+}
+
+/// Build a [`CsrGraph`] from borrowed `indptr`/`indices` numpy buffers,
+/// paying the `i64` -> `usize` conversion exactly once via
+/// [`CsrGraphView::to_owned`] rather than hand-rolling the two `Vec<usize>`
+/// collects on every call.
+pub(crate) fn build_graph(
+    indptr: &PyReadonlyArray1<i64>,
+    indices: &PyReadonlyArray1<i64>,
+) -> PyResult<CsrGraph> {
+    let indptr_slice = indptr.as_slice()?;
+    let indices_slice = indices.as_slice()?;
+    let n = indptr_slice.len().saturating_sub(1);
+    CsrGraphView::new(n, indptr_slice, indices_slice)
+        .to_owned()
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("{}", e)))
+}
 
 fn sssp_csr_impl_f32(
     py: Python,
     indptr: PyReadonlyArray1<i64>,
     indices: PyReadonlyArray1<i64>,
     weights: PyReadonlyArray1<f32>,
-    source: usize,
+    source: &Bound<'_, PyAny>,
+    source_dist: Option<PyReadonlyArray1<f32>>,
     enabled: Option<PyReadonlyArray1<u8>>,
     return_pred: bool,
+    target: Option<usize>,
 ) -> PyResult<PyObject> {
-    // Convert indptr and indices to Vec<usize>
-    let indptr_vec: Vec<usize> = indptr.as_slice()?.iter().map(|&x| x as usize).collect();
-    let indices_vec: Vec<usize> = indices.as_slice()?.iter().map(|&x| x as usize).collect();
-    
-    // Get n from indptr length
-    let n = indptr_vec.len() - 1;
-    
-    // Create graph
-    let graph = CsrGraph::new(n, indptr_vec, indices_vec)
-        .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("{}", e)))?;
-    
-    // Validate weights
+    let graph = build_graph(&indptr, &indices)?;
     let weights_slice = weights.as_slice()?;
-    validation::validate_weights_len(&graph, weights_slice.len())
+    query_sssp_f32(py, &graph, weights_slice, source, source_dist, enabled, return_pred, target)
+}
+
+/// Run an `f32` query against an already-built, already-validated graph.
+/// Shared by [`sssp_csr_impl_f32`] (which builds a fresh graph per call) and
+/// [`crate::handle::CsrHandle`] (which reuses one graph across many calls).
+pub(crate) fn query_sssp_f32(
+    py: Python,
+    graph: &CsrGraph,
+    weights_slice: &[f32],
+    source: &Bound<'_, PyAny>,
+    source_dist: Option<PyReadonlyArray1<f32>>,
+    enabled: Option<PyReadonlyArray1<u8>>,
+    return_pred: bool,
+    target: Option<usize>,
+) -> PyResult<PyObject> {
+    // Validate weights
+    validation::validate_weights_len(graph, weights_slice.len())
         .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("{}", e)))?;
     validation::validate_weights(weights_slice)
         .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("{}", e)))?;
-    validation::validate_source(&graph, source)
-        .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("{}", e)))?;
-    
+
     // Convert enabled mask if provided
     let enabled_mask: Option<Vec<bool>> = if let Some(enabled_arr) = enabled {
         let enabled_slice = enabled_arr.as_slice()?;
@@ -42,18 +93,112 @@ fn sssp_csr_impl_f32(
     } else {
         None
     };
-    
+
+    // Point-to-point mode: a target vertex was given, so run bidirectional
+    // Dijkstra instead of a full single-source expansion. Requires a scalar
+    // source and is incompatible with batched (2-D) source queries.
+    if let Some(target) = target {
+        if parse_source_rows(source)?.is_some() {
+            return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                "target is not supported for batched (2-D) source queries",
+            ));
+        }
+        let sources = parse_sources(source)?;
+        if sources.len() != 1 {
+            return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                "target requires a single scalar source vertex",
+            ));
+        }
+        validation::validate_sources(graph, &sources)
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("{}", e)))?;
+        validation::validate_source(graph, target)
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("{}", e)))?;
+
+        let found = bidirectional_sssp(graph, weights_slice, sources[0], target, enabled_mask.as_deref())
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("{}", e)))?;
+
+        let result = PyDict::new_bound(py);
+        match found {
+            Some((dist, path)) => {
+                result.set_item("distance", dist)?;
+                let path_i64: Vec<i64> = path.iter().map(|&v| v as i64).collect();
+                result.set_item("path", path_i64.into_pyarray_bound(py))?;
+            }
+            None => {
+                result.set_item("distance", f32::INFINITY)?;
+                result.set_item("path", Vec::<i64>::new().into_pyarray_bound(py))?;
+            }
+        }
+        return Ok(result.into_py(py));
+    }
+
+    // Batched mode: source is a 2-D array of shape [q, s], one row of seeds
+    // per query. Reuse the already-validated graph/weights across all rows
+    // and solve them concurrently with rayon.
+    if let Some(query_rows) = parse_source_rows(source)? {
+        if source_dist.is_some() {
+            return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                "source_dist is not supported for batched (2-D) source queries",
+            ));
+        }
+        for row in &query_rows {
+            validation::validate_sources(graph, row)
+                .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("{}", e)))?;
+        }
+
+        let results: Vec<BmsspResult<(Vec<f32>, Vec<usize>)>> = query_rows
+            .par_iter()
+            .map(|row| {
+                bmssp_sssp_with_preds_multi(graph, weights_slice, row, None, enabled_mask.as_deref())
+            })
+            .collect();
+
+        let mut dist_rows = Vec::with_capacity(results.len());
+        let mut pred_rows = Vec::with_capacity(results.len());
+        for result in results {
+            let (dist, pred) = result
+                .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("{}", e)))?;
+            dist_rows.push(dist);
+            if return_pred {
+                pred_rows.push(pred.iter().map(|&p| if p == usize::MAX { -1i32 } else { p as i32 }).collect::<Vec<i32>>());
+            }
+        }
+
+        let dist_array = PyArray2::from_vec2_bound(py, &dist_rows)?;
+        if return_pred {
+            let pred_array = PyArray2::from_vec2_bound(py, &pred_rows)?;
+            let result = PyDict::new_bound(py);
+            result.set_item("dist", dist_array.as_any())?;
+            result.set_item("pred", pred_array.as_any())?;
+            return Ok(result.into_py(py));
+        }
+        return Ok(dist_array.into_py(py));
+    }
+
+    let sources = parse_sources(source)?;
+    validation::validate_sources(graph, &sources)
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("{}", e)))?;
+
+    let initial_dist: Option<Vec<f32>> = source_dist
+        .map(|arr| -> PyResult<Vec<f32>> { Ok(arr.as_slice()?.to_vec()) })
+        .transpose()?;
+    if let Some(ref init) = initial_dist {
+        validation::validate_source_dist_len(sources.len(), init.len())
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("{}", e)))?;
+    }
+
     // Run BMSSP with predecessors if requested
-    let (dist, pred_vec) = bmssp_sssp_with_preds(
-        &graph,
+    let (dist, pred_vec) = bmssp_sssp_with_preds_multi(
+        graph,
         weights_slice,
-        source,
+        &sources,
+        initial_dist.as_deref(),
         enabled_mask.as_deref(),
     ).map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("{}", e)))?;
-    
+
     // Return distances as numpy array
     let dist_array = dist.into_pyarray_bound(py);
-    
+
     if return_pred {
         // Convert predecessors to i32 array (use -1 for unreachable)
         let pred_i32: Vec<i32> = pred_vec.iter().map(|&p| {
@@ -78,30 +223,36 @@ fn sssp_csr_impl_f64(
     indptr: PyReadonlyArray1<i64>,
     indices: PyReadonlyArray1<i64>,
     weights: PyReadonlyArray1<f64>,
-    source: usize,
+    source: &Bound<'_, PyAny>,
+    source_dist: Option<PyReadonlyArray1<f64>>,
     enabled: Option<PyReadonlyArray1<u8>>,
     return_pred: bool,
+    target: Option<usize>,
 ) -> PyResult<PyObject> {
-    // Convert indptr and indices to Vec<usize>
-    let indptr_vec: Vec<usize> = indptr.as_slice()?.iter().map(|&x| x as usize).collect();
-    let indices_vec: Vec<usize> = indices.as_slice()?.iter().map(|&x| x as usize).collect();
-    
-    // Get n from indptr length
-    let n = indptr_vec.len() - 1;
-    
-    // Create graph
-    let graph = CsrGraph::new(n, indptr_vec, indices_vec)
-        .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("{}", e)))?;
-    
-    // Validate weights
+    let graph = build_graph(&indptr, &indices)?;
     let weights_slice = weights.as_slice()?;
-    validation::validate_weights_len(&graph, weights_slice.len())
+    query_sssp_f64(py, &graph, weights_slice, source, source_dist, enabled, return_pred, target)
+}
+
+/// Run an `f64` query against an already-built, already-validated graph.
+/// Shared by [`sssp_csr_impl_f64`] (which builds a fresh graph per call) and
+/// [`crate::handle::CsrHandle`] (which reuses one graph across many calls).
+pub(crate) fn query_sssp_f64(
+    py: Python,
+    graph: &CsrGraph,
+    weights_slice: &[f64],
+    source: &Bound<'_, PyAny>,
+    source_dist: Option<PyReadonlyArray1<f64>>,
+    enabled: Option<PyReadonlyArray1<u8>>,
+    return_pred: bool,
+    target: Option<usize>,
+) -> PyResult<PyObject> {
+    // Validate weights
+    validation::validate_weights_len(graph, weights_slice.len())
         .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("{}", e)))?;
     validation::validate_weights(weights_slice)
         .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("{}", e)))?;
-    validation::validate_source(&graph, source)
-        .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("{}", e)))?;
-    
+
     // Convert enabled mask if provided
     let enabled_mask: Option<Vec<bool>> = if let Some(enabled_arr) = enabled {
         let enabled_slice = enabled_arr.as_slice()?;
@@ -112,18 +263,112 @@ fn sssp_csr_impl_f64(
     } else {
         None
     };
-    
+
+    // Point-to-point mode: a target vertex was given, so run bidirectional
+    // Dijkstra instead of a full single-source expansion. Requires a scalar
+    // source and is incompatible with batched (2-D) source queries.
+    if let Some(target) = target {
+        if parse_source_rows(source)?.is_some() {
+            return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                "target is not supported for batched (2-D) source queries",
+            ));
+        }
+        let sources = parse_sources(source)?;
+        if sources.len() != 1 {
+            return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                "target requires a single scalar source vertex",
+            ));
+        }
+        validation::validate_sources(graph, &sources)
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("{}", e)))?;
+        validation::validate_source(graph, target)
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("{}", e)))?;
+
+        let found = bidirectional_sssp(graph, weights_slice, sources[0], target, enabled_mask.as_deref())
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("{}", e)))?;
+
+        let result = PyDict::new_bound(py);
+        match found {
+            Some((dist, path)) => {
+                result.set_item("distance", dist)?;
+                let path_i64: Vec<i64> = path.iter().map(|&v| v as i64).collect();
+                result.set_item("path", path_i64.into_pyarray_bound(py))?;
+            }
+            None => {
+                result.set_item("distance", f64::INFINITY)?;
+                result.set_item("path", Vec::<i64>::new().into_pyarray_bound(py))?;
+            }
+        }
+        return Ok(result.into_py(py));
+    }
+
+    // Batched mode: source is a 2-D array of shape [q, s], one row of seeds
+    // per query. Reuse the already-validated graph/weights across all rows
+    // and solve them concurrently with rayon.
+    if let Some(query_rows) = parse_source_rows(source)? {
+        if source_dist.is_some() {
+            return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                "source_dist is not supported for batched (2-D) source queries",
+            ));
+        }
+        for row in &query_rows {
+            validation::validate_sources(graph, row)
+                .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("{}", e)))?;
+        }
+
+        let results: Vec<BmsspResult<(Vec<f64>, Vec<usize>)>> = query_rows
+            .par_iter()
+            .map(|row| {
+                bmssp_sssp_with_preds_multi(graph, weights_slice, row, None, enabled_mask.as_deref())
+            })
+            .collect();
+
+        let mut dist_rows = Vec::with_capacity(results.len());
+        let mut pred_rows = Vec::with_capacity(results.len());
+        for result in results {
+            let (dist, pred) = result
+                .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("{}", e)))?;
+            dist_rows.push(dist);
+            if return_pred {
+                pred_rows.push(pred.iter().map(|&p| if p == usize::MAX { -1i32 } else { p as i32 }).collect::<Vec<i32>>());
+            }
+        }
+
+        let dist_array = PyArray2::from_vec2_bound(py, &dist_rows)?;
+        if return_pred {
+            let pred_array = PyArray2::from_vec2_bound(py, &pred_rows)?;
+            let result = PyDict::new_bound(py);
+            result.set_item("dist", dist_array.as_any())?;
+            result.set_item("pred", pred_array.as_any())?;
+            return Ok(result.into_py(py));
+        }
+        return Ok(dist_array.into_py(py));
+    }
+
+    let sources = parse_sources(source)?;
+    validation::validate_sources(graph, &sources)
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("{}", e)))?;
+
+    let initial_dist: Option<Vec<f64>> = source_dist
+        .map(|arr| -> PyResult<Vec<f64>> { Ok(arr.as_slice()?.to_vec()) })
+        .transpose()?;
+    if let Some(ref init) = initial_dist {
+        validation::validate_source_dist_len(sources.len(), init.len())
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("{}", e)))?;
+    }
+
     // Run BMSSP with predecessors if requested
-    let (dist, pred_vec) = bmssp_sssp_with_preds(
-        &graph,
+    let (dist, pred_vec) = bmssp_sssp_with_preds_multi(
+        graph,
         weights_slice,
-        source,
+        &sources,
+        initial_dist.as_deref(),
         enabled_mask.as_deref(),
     ).map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("{}", e)))?;
-    
+
     // Return distances as numpy array
     let dist_array = dist.into_pyarray_bound(py);
-    
+
     if return_pred {
         // Convert predecessors to i32 array (use -1 for unreachable)
         let pred_i32: Vec<i32> = pred_vec.iter().map(|&p| {
@@ -144,29 +389,108 @@ fn sssp_csr_impl_f64(
 }
 
 #[pyfunction]
-#[pyo3(signature = (indptr, indices, weights, source, enabled = None, return_pred = false))]
+#[pyo3(signature = (indptr, indices, weights, source, source_dist = None, enabled = None, return_pred = false, target = None))]
 pub fn sssp_f32_csr(
     py: Python,
     indptr: PyReadonlyArray1<i64>,
     indices: PyReadonlyArray1<i64>,
     weights: PyReadonlyArray1<f32>,
-    source: usize,
+    source: &Bound<'_, PyAny>,
+    source_dist: Option<PyReadonlyArray1<f32>>,
     enabled: Option<PyReadonlyArray1<u8>>,
     return_pred: bool,
+    target: Option<usize>,
 ) -> PyResult<PyObject> {
-    sssp_csr_impl_f32(py, indptr, indices, weights, source, enabled, return_pred)
+    sssp_csr_impl_f32(py, indptr, indices, weights, source, source_dist, enabled, return_pred, target)
 }
 
 #[pyfunction]
-#[pyo3(signature = (indptr, indices, weights, source, enabled = None, return_pred = false))]
+#[pyo3(signature = (indptr, indices, weights, source, source_dist = None, enabled = None, return_pred = false, target = None))]
 pub fn sssp_f64_csr(
     py: Python,
     indptr: PyReadonlyArray1<i64>,
     indices: PyReadonlyArray1<i64>,
     weights: PyReadonlyArray1<f64>,
+    source: &Bound<'_, PyAny>,
+    source_dist: Option<PyReadonlyArray1<f64>>,
+    enabled: Option<PyReadonlyArray1<u8>>,
+    return_pred: bool,
+    target: Option<usize>,
+) -> PyResult<PyObject> {
+    sssp_csr_impl_f64(py, indptr, indices, weights, source, source_dist, enabled, return_pred, target)
+}
+
+/// Single-source shortest paths for integer (or pre-quantized) edge
+/// weights, using Dial's bucket queue instead of a comparison-based heap.
+///
+/// Intended for callers that detect an integer weight dtype and want the
+/// O(m + C) bucket-heap path rather than `sssp_f32_csr`/`sssp_f64_csr`'s
+/// comparison-based solvers.
+#[pyfunction]
+#[pyo3(signature = (indptr, indices, weights, source, enabled = None, return_pred = false))]
+pub fn sssp_i64_csr(
+    py: Python,
+    indptr: PyReadonlyArray1<i64>,
+    indices: PyReadonlyArray1<i64>,
+    weights: PyReadonlyArray1<i64>,
     source: usize,
     enabled: Option<PyReadonlyArray1<u8>>,
     return_pred: bool,
 ) -> PyResult<PyObject> {
-    sssp_csr_impl_f64(py, indptr, indices, weights, source, enabled, return_pred)
+    let graph = build_graph(&indptr, &indices)?;
+
+    let weights_slice = weights.as_slice()?;
+    validation::validate_weights_len(&graph, weights_slice.len())
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("{}", e)))?;
+    validation::validate_source(&graph, source)
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("{}", e)))?;
+
+    let mut max_weight: i64 = 0;
+    for &w in weights_slice {
+        if w < 0 {
+            return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                "sssp_i64_csr requires non-negative integer weights",
+            ));
+        }
+        max_weight = max_weight.max(w);
+    }
+    let weights_usize: Vec<usize> = weights_slice.iter().map(|&w| w as usize).collect();
+
+    let enabled_mask: Option<Vec<bool>> = if let Some(enabled_arr) = enabled {
+        let enabled_slice = enabled_arr.as_slice()?;
+        let enabled_bool: Vec<bool> = enabled_slice.iter().map(|&x| x != 0).collect();
+        validation::validate_enabled_mask(graph.num_edges(), &enabled_bool)
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("{}", e)))?;
+        Some(enabled_bool)
+    } else {
+        None
+    };
+
+    let (dist, pred_vec) = dijkstra_sssp_bucket_with_preds(
+        &graph,
+        &weights_usize,
+        source,
+        enabled_mask.as_deref(),
+        max_weight as usize,
+    ).map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("{}", e)))?;
+
+    let dist_i64: Vec<i64> = dist.iter().map(|&d| if d == usize::MAX { -1 } else { d as i64 }).collect();
+    let dist_array = dist_i64.into_pyarray_bound(py);
+
+    if return_pred {
+        let pred_i32: Vec<i32> = pred_vec.iter().map(|&p| {
+            if p == usize::MAX {
+                -1i32
+            } else {
+                p as i32
+            }
+        }).collect();
+        let pred_array = pred_i32.into_pyarray_bound(py);
+        let result = PyDict::new_bound(py);
+        result.set_item("dist", dist_array.as_any())?;
+        result.set_item("pred", pred_array.as_any())?;
+        Ok(result.into_py(py))
+    } else {
+        Ok(dist_array.into_py(py))
+    }
 }